@@ -0,0 +1,302 @@
+//! The slice of the Telegram Bot API the bot uses.
+//!
+//! Every call goes through the [`BotApi`] trait. [`HttpBotApi`] is the real
+//! client; its base URL can point at a local server, which is how the tests
+//! run it.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+
+pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
+
+/// How long a call other than a long poll may take.
+const CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The Bot API methods the bot calls.
+pub trait BotApi {
+    /// Long-polls for updates after `offset`, waiting up to `timeout` seconds.
+    fn get_updates(&self, offset: i64, timeout: u32) -> Result<Vec<Update>, ApiError>;
+    fn send_message(&self, message: &SendMessage) -> Result<(), ApiError>;
+    fn send_photo(&self, photo: &SendPhoto) -> Result<(), ApiError>;
+    /// Sends 2–10 photos as one album.
+    fn send_media_group(&self, group: &SendMediaGroup) -> Result<(), ApiError>;
+}
+
+/// Why a Bot API call failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    /// The request did not reach Telegram or the answer did not come back.
+    Transport(String),
+    /// Telegram refused the call, e.g. "Bad Request: chat not found".
+    Telegram(String),
+    /// The answer was not what the method returns.
+    Decode(String),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Transport(err) => write!(f, "request failed: {err}"),
+            ApiError::Telegram(description) => write!(f, "Telegram refused: {description}"),
+            ApiError::Decode(err) => write!(f, "unexpected answer: {err}"),
+        }
+    }
+}
+
+impl Error for ApiError {}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Update {
+    pub update_id: i64,
+    pub message: Option<Message>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Message {
+    pub chat: Chat,
+    pub from: Option<User>,
+    pub text: Option<String>,
+    /// What the mini app sent with `WebApp.sendData`.
+    pub web_app_data: Option<WebAppData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Chat {
+    pub id: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct User {
+    pub id: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebAppData {
+    pub data: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SendMessage {
+    pub chat_id: i64,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyKeyboardMarkup>,
+}
+
+/// A keyboard shown in place of the user's. Only a `web_app` button on it
+/// opens the mini app in a way that lets it send data back.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReplyKeyboardMarkup {
+    pub keyboard: Vec<Vec<KeyboardButton>>,
+    pub resize_keyboard: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KeyboardButton {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebAppInfo {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SendPhoto {
+    pub chat_id: i64,
+    /// URL Telegram downloads the photo from.
+    pub photo: String,
+    pub caption: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SendMediaGroup {
+    pub chat_id: i64,
+    pub media: Vec<InputMediaPhoto>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InputMediaPhoto {
+    /// Always `photo`.
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub media: String,
+    pub caption: String,
+}
+
+impl InputMediaPhoto {
+    pub fn new(media: String, caption: String) -> Self {
+        Self {
+            kind: "photo",
+            media,
+            caption,
+        }
+    }
+}
+
+/// The envelope of every Bot API answer.
+#[derive(Deserialize)]
+struct Answer<T> {
+    ok: bool,
+    result: Option<T>,
+    description: Option<String>,
+}
+
+/// [`BotApi`] over HTTPS, one JSON `POST` per call.
+pub struct HttpBotApi {
+    agent: ureq::Agent,
+    base_url: String,
+    token: String,
+}
+
+impl HttpBotApi {
+    /// A client for `token` against `base_url`, normally [`TELEGRAM_API_URL`].
+    pub fn new(base_url: &str, token: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout_connect(Duration::from_secs(10))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+        }
+    }
+
+    fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        body: &impl Serialize,
+        timeout: Duration,
+    ) -> Result<T, ApiError> {
+        let url = format!("{}/bot{}/{method}", self.base_url, self.token);
+        let response = match self.agent.post(&url).timeout(timeout).send_json(body) {
+            Ok(response) => response,
+            // Telegram explains refusals in the body of 4xx answers.
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(ApiError::Transport(err.to_string())),
+        };
+        let answer: Answer<T> = response
+            .into_json()
+            .map_err(|err| ApiError::Decode(err.to_string()))?;
+
+        match answer {
+            Answer {
+                ok: true,
+                result: Some(result),
+                ..
+            } => Ok(result),
+            Answer {
+                ok: true,
+                result: None,
+                ..
+            } => Err(ApiError::Decode(format!("`{method}` returned no result"))),
+            Answer { description, .. } => Err(ApiError::Telegram(
+                description.unwrap_or_else(|| format!("`{method}` failed")),
+            )),
+        }
+    }
+}
+
+impl BotApi for HttpBotApi {
+    fn get_updates(&self, offset: i64, timeout: u32) -> Result<Vec<Update>, ApiError> {
+        #[derive(Serialize)]
+        struct GetUpdates {
+            offset: i64,
+            timeout: u32,
+            allowed_updates: [&'static str; 1],
+        }
+
+        let body = GetUpdates {
+            offset,
+            timeout,
+            allowed_updates: ["message"],
+        };
+        // Telegram holds the request for up to `timeout` seconds.
+        let wait = CALL_TIMEOUT + Duration::from_secs(timeout.into());
+        self.call("getUpdates", &body, wait)
+    }
+
+    fn send_message(&self, message: &SendMessage) -> Result<(), ApiError> {
+        self.call::<IgnoredAny>("sendMessage", message, CALL_TIMEOUT)
+            .map(drop)
+    }
+
+    fn send_photo(&self, photo: &SendPhoto) -> Result<(), ApiError> {
+        self.call::<IgnoredAny>("sendPhoto", photo, CALL_TIMEOUT)
+            .map(drop)
+    }
+
+    fn send_media_group(&self, group: &SendMediaGroup) -> Result<(), ApiError> {
+        self.call::<IgnoredAny>("sendMediaGroup", group, CALL_TIMEOUT)
+            .map(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    #[test]
+    fn updates_carry_commands_and_web_app_data() {
+        let server = MockServer::start();
+        server.answer(
+            "getUpdates",
+            json!({ "ok": true, "result": [
+                { "update_id": 7, "message": {
+                    "message_id": 1, "chat": { "id": 42 },
+                    "from": { "id": 5, "first_name": "Ada", "language_code": "uk" },
+                    "text": "/draw 3"
+                } },
+                { "update_id": 8, "message": {
+                    "message_id": 2, "chat": { "id": 42 },
+                    "web_app_data": { "data": "2|single|en|00|-|", "button_text": "Open" }
+                } }
+            ] }),
+        );
+        let api = HttpBotApi::new(&server.url(), "TOKEN");
+
+        let updates = api.get_updates(7, 0).unwrap();
+
+        assert_eq!(updates.len(), 2);
+        let first = updates[0].message.as_ref().unwrap();
+        assert_eq!(first.text.as_deref(), Some("/draw 3"));
+        assert_eq!(first.from.as_ref().unwrap().id, 5);
+        let data = updates[1].message.as_ref().unwrap().web_app_data.as_ref();
+        assert_eq!(data.unwrap().data, "2|single|en|00|-|");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/botTOKEN/getUpdates");
+        assert_eq!(requests[0].body["offset"], 7);
+    }
+
+    #[test]
+    fn refusals_report_telegrams_description() {
+        let server = MockServer::start();
+        server.answer_with_status(
+            "sendMessage",
+            400,
+            json!({ "ok": false, "error_code": 400, "description": "Bad Request: chat not found" }),
+        );
+        let api = HttpBotApi::new(&server.url(), "TOKEN");
+
+        let message = SendMessage {
+            chat_id: 1,
+            text: "hi".to_string(),
+            reply_markup: None,
+        };
+        assert_eq!(
+            api.send_message(&message),
+            Err(ApiError::Telegram(
+                "Bad Request: chat not found".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! The HTTP backend the mini app calls.
+//!
+//! Every route sits behind `initData` verification: the app sends its raw
+//! `WebApp.initData` as `Authorization: tma <initData>`, and a request that
+//! does not verify is answered 401 before any route runs.
+//!
+//! - `GET /api/me` answers the verified user.
+//! - `GET /api/daily?date=YYYY-MM-DD` answers their card of the day, with the
+//!   seed the app deals it from. `date` is the user's local date, so the card
+//!   turns over at their midnight; without it the day is the UTC one.
+
+use std::error::Error;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+use tg_tarot_app::daily::{daily_seed, LocalDate};
+use tg_tarot_app::deck::{Deck, Orientation, ReversalPolicy, DAILY_SPREAD};
+use tiny_http::{Header, Method, Response, Server};
+
+use crate::init_data::{self, TelegramUser};
+
+/// How long signed `initData` stays valid. Telegram signs it when the app
+/// opens, so this bounds how long one session may keep calling.
+const MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// What the backend answers one request with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Reply {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message }),
+        }
+    }
+}
+
+pub struct Backend {
+    bot_token: String,
+    /// The mini app's origin, the only one browsers may call from.
+    allowed_origin: String,
+}
+
+impl Backend {
+    /// A backend for the bot with `bot_token`, called by the mini app served
+    /// at `web_app_url`.
+    pub fn new(bot_token: &str, web_app_url: &str) -> Self {
+        Self {
+            bot_token: bot_token.to_string(),
+            allowed_origin: origin(web_app_url).to_string(),
+        }
+    }
+
+    /// Answers one call to `url`, a path with an optional query.
+    /// Authentication comes first, so no route can skip it.
+    pub fn respond(
+        &self,
+        method: &str,
+        url: &str,
+        authorization: Option<&str>,
+        now: SystemTime,
+    ) -> Reply {
+        let user = match self.authenticate(authorization, now) {
+            Ok(user) => user,
+            Err(message) => return Reply::error(401, &message),
+        };
+
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        match (method, path) {
+            ("GET", "/api/me") => Reply::ok(json!(user)),
+            ("GET", "/api/daily") => daily_card(&user, query, now),
+            _ => Reply::error(404, "no such route"),
+        }
+    }
+
+    fn authenticate(
+        &self,
+        authorization: Option<&str>,
+        now: SystemTime,
+    ) -> Result<TelegramUser, String> {
+        let init_data = authorization
+            .and_then(|header| header.strip_prefix("tma "))
+            .ok_or("send `Authorization: tma <initData>`")?;
+        init_data::validate(init_data, &self.bot_token, MAX_AGE, now).map_err(|err| err.to_string())
+    }
+
+    /// Serves HTTP on `address` until the process ends.
+    pub fn serve(&self, address: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let server = Server::http(address)?;
+        for request in server.incoming_requests() {
+            let mut headers = vec![
+                header("Access-Control-Allow-Origin", &self.allowed_origin),
+                header("Access-Control-Allow-Headers", "Authorization"),
+            ];
+            // Browsers ask before sending `Authorization` across origins.
+            if *request.method() == Method::Options {
+                let mut response = Response::empty(204);
+                for header in headers {
+                    response.add_header(header);
+                }
+                let _ = request.respond(response);
+                continue;
+            }
+
+            let authorization = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Authorization"))
+                .map(|header| header.value.to_string());
+            let reply = self.respond(
+                request.method().as_str(),
+                request.url(),
+                authorization.as_deref(),
+                SystemTime::now(),
+            );
+
+            headers.push(header("Content-Type", "application/json"));
+            let mut response =
+                Response::from_string(reply.body.to_string()).with_status_code(reply.status);
+            for header in headers {
+                response.add_header(header);
+            }
+            if let Err(err) = request.respond(response) {
+                eprintln!("backend: {err}");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The verified user's card of the day, dealt like the mini app's. `query`
+/// may give their local `date`; without it the day is the UTC one, and the
+/// card matches the mini app's only while their local date is the UTC date.
+///
+/// The seed is a string, as JavaScript numbers cannot hold every `u64`.
+fn daily_card(user: &TelegramUser, query: &str, now: SystemTime) -> Reply {
+    let utc_days = now
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86_400) as i64;
+    let date = match form_urlencoded::parse(query.as_bytes()).find(|(key, _)| key == "date") {
+        None => LocalDate::from_days_since_epoch(utc_days),
+        Some((_, date)) => match date.parse::<LocalDate>() {
+            // Every time zone is within a day of UTC, so no one's today is further.
+            Ok(date) if (date.days_since_epoch() - utc_days).abs() <= 1 => date,
+            Ok(_) => return Reply::error(400, "`date` is not today anywhere"),
+            Err(()) => return Reply::error(400, "`date` must be YYYY-MM-DD"),
+        },
+    };
+
+    let seed = daily_seed(user.id, date);
+    let deal = Deck::standard().draw_seeded(
+        &DAILY_SPREAD,
+        DAILY_SPREAD.reversal_policy(ReversalPolicy::default()),
+        seed,
+    );
+    match deal.map(|deal| deal.cards) {
+        Ok(cards) => {
+            let card = cards[0];
+            Reply::ok(json!({
+                "date": date.to_string(),
+                "seed": seed.to_string(),
+                "card": card.card.slug,
+                "reversed": card.orientation == Orientation::Reversed,
+            }))
+        }
+        Err(err) => Reply::error(500, &err.to_string()),
+    }
+}
+
+/// `https://host[:port]` of a URL.
+fn origin(url: &str) -> &str {
+    let start = url.find("://").map_or(0, |scheme| scheme + 3);
+    match url[start..].find('/') {
+        Some(path) => &url[..start + path],
+        None => url,
+    }
+}
+
+fn header(field: &str, value: &str) -> Header {
+    Header::from_bytes(field, value).expect("ASCII header")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+    use crate::init_data::fixture::{BOT_TOKEN, SIGNED, SIGNED_AT};
+
+    fn backend() -> Backend {
+        Backend::new(BOT_TOKEN, "https://tarot.example/app/")
+    }
+
+    fn soon_after_signing() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(SIGNED_AT + 60)
+    }
+
+    #[test]
+    fn every_route_requires_signed_init_data() {
+        let backend = backend();
+        let now = soon_after_signing();
+        let forged = format!("tma {}", SIGNED.replace("279058397", "1"));
+
+        for path in ["/api/me", "/api/daily", "/api/unknown"] {
+            assert_eq!(
+                backend.respond("GET", path, None, now).status,
+                401,
+                "{path}"
+            );
+            let reply = backend.respond("GET", path, Some(&forged), now);
+            assert_eq!(reply.status, 401, "{path}");
+        }
+    }
+
+    #[test]
+    fn signed_calls_reach_the_routes() {
+        let backend = backend();
+        let authorization = format!("tma {SIGNED}");
+        let me = backend.respond("GET", "/api/me", Some(&authorization), soon_after_signing());
+
+        assert_eq!(me.status, 200);
+        assert_eq!(me.body["id"], 279058397);
+        assert_eq!(me.body["username"], "ada");
+
+        let daily = backend.respond(
+            "GET",
+            "/api/daily",
+            Some(&authorization),
+            soon_after_signing(),
+        );
+        assert_eq!(daily.status, 200);
+        assert!(daily.body["card"].is_string());
+        assert_eq!(daily.body["date"], "2023-11-14");
+    }
+
+    #[test]
+    fn the_daily_card_follows_the_users_local_date() {
+        let backend = backend();
+        let authorization = format!("tma {SIGNED}");
+        let daily = |url| backend.respond("GET", url, Some(&authorization), soon_after_signing());
+
+        // Signed at 22:13 UTC on 2023-11-14, already the 15th east of UTC+2.
+        let tomorrow = daily("/api/daily?date=2023-11-15");
+        assert_eq!(tomorrow.status, 200);
+        let date = LocalDate {
+            year: 2023,
+            month: 11,
+            day: 15,
+        };
+        assert_eq!(
+            tomorrow.body["seed"],
+            daily_seed(279058397, date).to_string()
+        );
+
+        assert_eq!(daily("/api/daily?date=2023-11-17").status, 400);
+        assert_eq!(daily("/api/daily?date=soon").status, 400);
+    }
+
+    #[test]
+    fn only_the_mini_app_origin_is_allowed() {
+        assert_eq!(backend().allowed_origin, "https://tarot.example");
+        assert_eq!(origin("http://localhost:8080"), "http://localhost:8080");
+    }
+}
@@ -0,0 +1,370 @@
+//! What the bot answers: its commands, and the readings the mini app sends
+//! back with `WebApp.sendData`.
+
+use std::thread;
+use std::time::Duration;
+
+use tg_tarot_app::daily::{daily_seed, LocalDate};
+use tg_tarot_app::deck::{
+    Deck, DrawnCard, Orientation, ReversalPolicy, Spread, TarotCard, DAILY_SPREAD,
+    SIGNIFICATOR_POSITION, SPREADS,
+};
+use tg_tarot_app::payload::ReadingPayload;
+
+use crate::api::{
+    ApiError, BotApi, InputMediaPhoto, KeyboardButton, ReplyKeyboardMarkup, SendMediaGroup,
+    SendMessage, SendPhoto, Update, WebAppInfo,
+};
+
+/// Seconds each long poll waits for updates.
+const POLL_TIMEOUT: u32 = 50;
+/// Pause after a failed poll, so a network outage is not a busy loop.
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+/// Cards `/draw` deals without a count.
+const DEFAULT_DRAW: usize = 3;
+/// Telegram albums hold at most this many photos.
+const ALBUM_SIZE: usize = 10;
+
+const OPEN_BUTTON: &str = "🔮 Open the deck";
+const WELCOME: &str = "Tap “Open the deck” for a full reading, or try /draw 3 or /daily.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Start,
+    /// `/draw <count>`. A count that is not a number is 0, which no spread has.
+    Draw {
+        count: usize,
+    },
+    Daily,
+}
+
+impl Command {
+    /// Reads a command from message text, ignoring a `@BotName` suffix.
+    fn parse(text: &str) -> Option<Self> {
+        let mut words = text.split_whitespace();
+        let command = words.next()?.strip_prefix('/')?;
+        let command = command.split('@').next().unwrap_or_default();
+        match command {
+            "start" | "help" => Some(Command::Start),
+            "draw" => Some(Command::Draw {
+                count: words
+                    .next()
+                    .map_or(DEFAULT_DRAW, |count| count.parse().unwrap_or(0)),
+            }),
+            "daily" => Some(Command::Daily),
+            _ => None,
+        }
+    }
+}
+
+pub struct Bot<A> {
+    api: A,
+    /// Where the mini app is served; card images are under `assets/` there.
+    web_app_url: String,
+}
+
+impl<A: BotApi> Bot<A> {
+    pub fn new(api: A, web_app_url: &str) -> Self {
+        Self {
+            api,
+            web_app_url: web_app_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Long-polls Telegram and answers every update, forever. Failures are
+    /// logged and never stop the bot.
+    pub fn run(&self) -> ! {
+        let mut offset = 0;
+        loop {
+            match self.api.get_updates(offset, POLL_TIMEOUT) {
+                Ok(updates) => {
+                    for update in updates {
+                        offset = update.update_id + 1;
+                        if let Err(err) = self.handle(&update) {
+                            eprintln!("update {}: {err}", update.update_id);
+                        }
+                    }
+                }
+                Err(err) => {
+                    eprintln!("getUpdates: {err}");
+                    thread::sleep(RETRY_DELAY);
+                }
+            }
+        }
+    }
+
+    pub fn handle(&self, update: &Update) -> Result<(), ApiError> {
+        let Some(message) = &update.message else {
+            return Ok(());
+        };
+        let chat = message.chat.id;
+        if let Some(data) = &message.web_app_data {
+            return self.reading_received(chat, &data.data);
+        }
+
+        match message.text.as_deref().and_then(Command::parse) {
+            Some(Command::Start) => self.start(chat),
+            Some(Command::Draw { count }) => self.draw(chat, count),
+            // Without a sender every chat shares one card, as outside Telegram.
+            Some(Command::Daily) => {
+                self.daily(chat, message.from.as_ref().map_or(0, |user| user.id))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Greets the user and puts the mini app on their keyboard. Only an app
+    /// opened from a keyboard button can send its reading back.
+    fn start(&self, chat: i64) -> Result<(), ApiError> {
+        self.api.send_message(&SendMessage {
+            chat_id: chat,
+            text: WELCOME.to_string(),
+            reply_markup: Some(ReplyKeyboardMarkup {
+                keyboard: vec![vec![KeyboardButton {
+                    text: OPEN_BUTTON.to_string(),
+                    web_app: Some(WebAppInfo {
+                        url: self.web_app_url.clone(),
+                    }),
+                }]],
+                resize_keyboard: true,
+            }),
+        })
+    }
+
+    /// Deals the first spread with `count` cards.
+    fn draw(&self, chat: i64, count: usize) -> Result<(), ApiError> {
+        let Some(spread) = SPREADS.iter().find(|spread| spread.card_count() == count) else {
+            let mut counts: Vec<_> = SPREADS.iter().map(Spread::card_count).collect();
+            counts.sort_unstable();
+            counts.dedup();
+            let counts = counts
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            return self.say(chat, format!("I can draw {counts} cards, e.g. /draw 3."));
+        };
+        self.deal(chat, spread, Deck::random_seed())
+    }
+
+    /// Today's card for `user`, dealt from the same seed and pinned reversal
+    /// policy as the mini app's. The server knows no time zone for the user,
+    /// so its day turns over at midnight UTC: the card matches the mini app's
+    /// only while the user's local date is the UTC date.
+    fn daily(&self, chat: i64, user: i64) -> Result<(), ApiError> {
+        self.deal(chat, &DAILY_SPREAD, daily_seed(user, LocalDate::today()))
+    }
+
+    fn deal(&self, chat: i64, spread: &'static Spread, seed: u64) -> Result<(), ApiError> {
+        let reversals = spread.reversal_policy(ReversalPolicy::default());
+        match Deck::standard().draw_seeded(spread, reversals, seed) {
+            Ok(deal) => {
+                self.say(chat, describe(spread.label, None, None, &deal.cards))?;
+                self.show_cards(chat, &deal.cards)
+            }
+            Err(err) => self.say(chat, err.to_string()),
+        }
+    }
+
+    /// Confirms a reading sent from the mini app.
+    fn reading_received(&self, chat: i64, data: &str) -> Result<(), ApiError> {
+        match ReadingPayload::decode(data) {
+            Ok(reading) => {
+                let title = format!("Your reading: {}", reading.spread.label);
+                let text = describe(
+                    &title,
+                    reading.question.as_deref(),
+                    reading.significator,
+                    &reading.cards,
+                );
+                self.say(chat, text)
+            }
+            Err(err) => self.say(chat, format!("Could not read that reading. {err}")),
+        }
+    }
+
+    /// Sends the card images: a single photo, or albums of up to ten.
+    fn show_cards(&self, chat: i64, cards: &[DrawnCard]) -> Result<(), ApiError> {
+        if let [card] = cards {
+            return self.api.send_photo(&SendPhoto {
+                chat_id: chat,
+                photo: self.image_url(card),
+                caption: card_line(card),
+            });
+        }
+
+        for album in cards.chunks(ALBUM_SIZE) {
+            let media = album
+                .iter()
+                .map(|card| InputMediaPhoto::new(self.image_url(card), card_line(card)))
+                .collect();
+            self.api.send_media_group(&SendMediaGroup {
+                chat_id: chat,
+                media,
+            })?;
+        }
+        Ok(())
+    }
+
+    fn image_url(&self, card: &DrawnCard) -> String {
+        format!("{}/{}", self.web_app_url, card.image_path())
+    }
+
+    fn say(&self, chat: i64, text: String) -> Result<(), ApiError> {
+        self.api.send_message(&SendMessage {
+            chat_id: chat,
+            text,
+            reply_markup: None,
+        })
+    }
+}
+
+/// `title`, the question if there is one, then one line per card.
+fn describe(
+    title: &str,
+    question: Option<&str>,
+    significator: Option<&TarotCard>,
+    cards: &[DrawnCard],
+) -> String {
+    let mut lines = vec![title.to_string()];
+    lines.extend(question.map(|question| format!("“{question}”")));
+    lines
+        .extend(significator.map(|card| format!("{}: {}", SIGNIFICATOR_POSITION.title, card.name)));
+    lines.extend(cards.iter().map(card_line));
+    lines.join("\n")
+}
+
+/// e.g. "Past: The Tower (reversed)".
+fn card_line(card: &DrawnCard) -> String {
+    let reversed = match card.orientation {
+        Orientation::Upright => "",
+        Orientation::Reversed => " (reversed)",
+    };
+    format!("{}: {}{reversed}", card.position.title, card.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tg_tarot_app::deck::CARDS;
+
+    use super::*;
+    use crate::api::HttpBotApi;
+    use crate::mock_server::MockServer;
+
+    const WEB_APP_URL: &str = "https://tarot.example/";
+
+    fn message(value: serde_json::Value) -> Update {
+        serde_json::from_value(json!({ "update_id": 1, "message": value })).unwrap()
+    }
+
+    fn bot(server: &MockServer) -> Bot<HttpBotApi> {
+        Bot::new(HttpBotApi::new(&server.url(), "TOKEN"), WEB_APP_URL)
+    }
+
+    #[test]
+    fn commands_are_parsed_with_or_without_the_bot_name() {
+        assert_eq!(Command::parse("/draw"), Some(Command::Draw { count: 3 }));
+        assert_eq!(
+            Command::parse("/draw@TarotBot 5"),
+            Some(Command::Draw { count: 5 })
+        );
+        assert_eq!(
+            Command::parse("/draw many"),
+            Some(Command::Draw { count: 0 })
+        );
+        assert_eq!(Command::parse("/daily"), Some(Command::Daily));
+        assert_eq!(Command::parse("draw 3"), None);
+    }
+
+    #[test]
+    fn start_puts_the_mini_app_on_the_keyboard() {
+        let server = MockServer::start();
+        let update = message(json!({ "chat": { "id": 42 }, "text": "/start" }));
+
+        bot(&server).handle(&update).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let button = &requests[0].body["reply_markup"]["keyboard"][0][0];
+        assert_eq!(button["web_app"]["url"], "https://tarot.example");
+    }
+
+    #[test]
+    fn draw_names_the_cards_and_sends_their_images() {
+        let server = MockServer::start();
+        let update = message(json!({ "chat": { "id": 42 }, "text": "/draw 3" }));
+
+        bot(&server).handle(&update).unwrap();
+
+        let requests = server.requests();
+        let methods: Vec<_> = requests.iter().map(|request| request.method()).collect();
+        assert_eq!(methods, ["sendMessage", "sendMediaGroup"]);
+        let text = requests[0].body["text"].as_str().unwrap();
+        assert!(text.starts_with("Three cards\nPast: "), "{text}");
+        let media = requests[1].body["media"].as_array().unwrap();
+        assert_eq!(media.len(), 3);
+        let image = media[0]["media"].as_str().unwrap();
+        assert!(
+            image.starts_with("https://tarot.example/assets/"),
+            "{image}"
+        );
+    }
+
+    #[test]
+    fn daily_sends_one_photo_and_unknown_counts_are_explained() {
+        let server = MockServer::start();
+        let daily = message(json!({ "chat": { "id": 42 }, "from": { "id": 7 }, "text": "/daily" }));
+        let odd = message(json!({ "chat": { "id": 42 }, "text": "/draw 4" }));
+
+        bot(&server).handle(&daily).unwrap();
+        bot(&server).handle(&odd).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[1].method(), "sendPhoto");
+        assert!(requests[1].body["caption"]
+            .as_str()
+            .unwrap()
+            .starts_with("Today: "));
+        let text = requests[2].body["text"].as_str().unwrap();
+        assert_eq!(text, "I can draw 1, 3, 5, 7, 10 cards, e.g. /draw 3.");
+    }
+
+    #[test]
+    fn readings_from_the_mini_app_are_decoded() {
+        let server = MockServer::start();
+        let fool = &CARDS[0];
+        let data = format!("2|yes-no|en|{:02x}|-|Will it rain?", fool.index | 0x80);
+        let update = message(json!({ "chat": { "id": 42 }, "web_app_data": { "data": data } }));
+        let queen = CARDS
+            .iter()
+            .find(|card| card.slug == "queen-of-cups")
+            .unwrap();
+        let data = format!("2|single|en|{:02x}|{:02x}|", fool.index, queen.index);
+        let with_significator =
+            message(json!({ "chat": { "id": 42 }, "web_app_data": { "data": data } }));
+        let broken = message(json!({ "chat": { "id": 42 }, "web_app_data": { "data": "9|" } }));
+
+        bot(&server).handle(&update).unwrap();
+        bot(&server).handle(&with_significator).unwrap();
+        bot(&server).handle(&broken).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].body["text"],
+            format!(
+                "Your reading: 1 card\n“Will it rain?”\nAnswer: {} (reversed)",
+                fool.name
+            )
+        );
+        assert_eq!(
+            requests[1].body["text"],
+            format!(
+                "Your reading: Single card\nSignificator: {}\nFocus: {}",
+                queen.name, fool.name
+            )
+        );
+        let text = requests[2].body["text"].as_str().unwrap();
+        assert!(text.contains("version 9"), "{text}");
+    }
+}
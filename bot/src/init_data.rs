@@ -0,0 +1,183 @@
+//! Verification of the `initData` string Telegram hands the mini app.
+//!
+//! `initDataUnsafe` in the browser is whatever the client says it is. The raw
+//! `initData` carries a `hash`, an HMAC-SHA-256 signature keyed by the bot
+//! token, so the backend can check it came from Telegram untouched:
+//! <https://core.telegram.org/bots/webapps#validating-data-received-via-the-mini-app>.
+
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The Telegram user that opened the mini app, as Telegram signed it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TelegramUser {
+    pub id: i64,
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub username: Option<String>,
+    pub language_code: Option<String>,
+    #[serde(default)]
+    pub is_premium: bool,
+}
+
+/// Why `initData` was not accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitDataError {
+    /// A field the check needs is absent: `hash`, `auth_date` or `user`.
+    Missing(&'static str),
+    /// A field does not parse.
+    Malformed(&'static str),
+    /// The signature does not match: the data was changed, or signed with
+    /// another bot's token.
+    BadSignature,
+    /// The data was signed longer ago than the backend accepts.
+    Expired { age: Duration },
+}
+
+impl fmt::Display for InitDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitDataError::Missing(field) => write!(f, "initData has no `{field}`"),
+            InitDataError::Malformed(field) => write!(f, "initData has a malformed `{field}`"),
+            InitDataError::BadSignature => write!(f, "initData is not signed by this bot"),
+            InitDataError::Expired { age } => {
+                write!(f, "initData was signed {}s ago", age.as_secs())
+            }
+        }
+    }
+}
+
+impl Error for InitDataError {}
+
+/// Checks `init_data` against `bot_token` and returns the user it names.
+///
+/// Data signed more than `max_age` before `now` is refused, so a captured
+/// string cannot be replayed forever.
+pub fn validate(
+    init_data: &str,
+    bot_token: &str,
+    max_age: Duration,
+    now: SystemTime,
+) -> Result<TelegramUser, InitDataError> {
+    let mut hash = None;
+    let mut fields = Vec::new();
+    for (key, value) in form_urlencoded::parse(init_data.as_bytes()) {
+        if key == "hash" {
+            hash = Some(value.into_owned());
+        } else {
+            fields.push((key.into_owned(), value.into_owned()));
+        }
+    }
+    let hash = hash.ok_or(InitDataError::Missing("hash"))?;
+    let hash = hex::decode(hash).map_err(|_| InitDataError::Malformed("hash"))?;
+
+    // Every field but `hash`, sorted by key, as `key=value` lines.
+    fields.sort();
+    let check_string = fields
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let secret = HmacSha256::new_from_slice(b"WebAppData")
+        .expect("HMAC takes any key length")
+        .chain_update(bot_token.as_bytes())
+        .finalize()
+        .into_bytes();
+    HmacSha256::new_from_slice(&secret)
+        .expect("HMAC takes any key length")
+        .chain_update(check_string.as_bytes())
+        .verify_slice(&hash)
+        .map_err(|_| InitDataError::BadSignature)?;
+
+    let field = |name: &'static str| {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .ok_or(InitDataError::Missing(name))
+    };
+    let auth_date: u64 = field("auth_date")?
+        .parse()
+        .map_err(|_| InitDataError::Malformed("auth_date"))?;
+    let signed_at = UNIX_EPOCH + Duration::from_secs(auth_date);
+    // A date slightly ahead of our clock is skew, not age.
+    let age = now.duration_since(signed_at).unwrap_or_default();
+    if age > max_age {
+        return Err(InitDataError::Expired { age });
+    }
+
+    serde_json::from_str(field("user")?).map_err(|_| InitDataError::Malformed("user"))
+}
+
+/// Signed `initData` for tests, shared by every module that verifies it.
+#[cfg(test)]
+pub(crate) mod fixture {
+    /// The bot token [`SIGNED`] is signed with.
+    pub(crate) const BOT_TOKEN: &str = "123456:TEST-TOKEN";
+    /// When [`SIGNED`] was signed, its `auth_date`.
+    pub(crate) const SIGNED_AT: u64 = 1_700_000_000;
+    /// Ada Lovelace (id 279058397, `ada`) launching the app, signed with
+    /// [`BOT_TOKEN`] at [`SIGNED_AT`].
+    pub(crate) const SIGNED: &str = "query_id=AAHdF6IQAAAAAN0XohDhrOrc&user=%7B%22id%22%3A279058397%2C%22first_name%22%3A%22Ada%22%2C%22last_name%22%3A%22Lovelace%22%2C%22username%22%3A%22ada%22%2C%22language_code%22%3A%22uk%22%2C%22is_premium%22%3Atrue%7D&auth_date=1700000000&hash=59198df7debd4d0e727818a32149273d24ac873a80d400687b987d9b45f8f582";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixture::{BOT_TOKEN, SIGNED, SIGNED_AT};
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(86_400);
+
+    fn signed_after(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(SIGNED_AT + seconds)
+    }
+
+    #[test]
+    fn signed_data_yields_the_user() {
+        let user = validate(SIGNED, BOT_TOKEN, DAY, signed_after(60)).unwrap();
+
+        assert_eq!(user.id, 279058397);
+        assert_eq!(user.first_name, "Ada");
+        assert_eq!(user.username.as_deref(), Some("ada"));
+        assert_eq!(user.language_code.as_deref(), Some("uk"));
+        assert!(user.is_premium);
+    }
+
+    #[test]
+    fn tampered_or_foreign_data_is_refused() {
+        let now = signed_after(60);
+        let forged = SIGNED.replace("279058397", "1");
+        assert_eq!(
+            validate(&forged, BOT_TOKEN, DAY, now),
+            Err(InitDataError::BadSignature)
+        );
+        assert_eq!(
+            validate(SIGNED, "654321:OTHER-TOKEN", DAY, now),
+            Err(InitDataError::BadSignature)
+        );
+
+        let unsigned = SIGNED.split("&hash=").next().unwrap();
+        assert_eq!(
+            validate(unsigned, BOT_TOKEN, DAY, now),
+            Err(InitDataError::Missing("hash"))
+        );
+    }
+
+    #[test]
+    fn old_data_is_refused() {
+        assert_eq!(
+            validate(SIGNED, BOT_TOKEN, DAY, signed_after(86_401)),
+            Err(InitDataError::Expired {
+                age: Duration::from_secs(86_401)
+            })
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Companion bot for the tarot mini app.
+//!
+//! It answers `/draw <count>` and `/daily` with the same deck the mini app
+//! uses, puts the mini app on the keyboard with `/start`, and confirms the
+//! readings the mini app sends back. With `BACKEND_ADDR` set it also serves
+//! the HTTP backend the mini app calls, see [`backend`].
+//!
+//! Configuration comes from the environment:
+//! - `TELEGRAM_BOT_TOKEN` – the token from @BotFather;
+//! - `WEB_APP_URL` – where the mini app is served;
+//! - `TELEGRAM_API_URL` – optional, another Bot API server;
+//! - `BACKEND_ADDR` – optional, e.g. `0.0.0.0:8081`, where to serve the backend.
+
+mod api;
+mod backend;
+mod bot;
+mod init_data;
+#[cfg(test)]
+mod mock_server;
+
+use std::env;
+use std::process::ExitCode;
+use std::thread;
+
+use api::{HttpBotApi, TELEGRAM_API_URL};
+use backend::Backend;
+use bot::Bot;
+
+fn main() -> ExitCode {
+    let (Ok(token), Ok(web_app_url)) = (env::var("TELEGRAM_BOT_TOKEN"), env::var("WEB_APP_URL"))
+    else {
+        eprintln!("Set TELEGRAM_BOT_TOKEN and WEB_APP_URL.");
+        return ExitCode::FAILURE;
+    };
+    let api_url = env::var("TELEGRAM_API_URL").unwrap_or_else(|_| TELEGRAM_API_URL.to_string());
+
+    if let Ok(address) = env::var("BACKEND_ADDR") {
+        let backend = Backend::new(&token, &web_app_url);
+        thread::spawn(move || {
+            if let Err(err) = backend.serve(&address) {
+                eprintln!("backend on {address}: {err}");
+            }
+        });
+    }
+
+    Bot::new(HttpBotApi::new(&api_url, &token), &web_app_url).run()
+}
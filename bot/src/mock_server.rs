@@ -0,0 +1,108 @@
+//! A local stand-in for the Bot API that records every call, for tests.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Response, Server};
+
+/// One call the server received.
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    /// e.g. `/botTOKEN/sendMessage`.
+    pub path: String,
+    pub body: Value,
+}
+
+impl Recorded {
+    /// The Bot API method, e.g. `sendMessage`.
+    pub fn method(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// Status and body to answer a method with.
+    answers: HashMap<String, (u16, Value)>,
+    requests: Vec<Recorded>,
+}
+
+/// Serves on a free local port until dropped. Methods without a canned
+/// answer succeed with `true`.
+pub struct MockServer {
+    server: Arc<Server>,
+    state: Arc<Mutex<State>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn start() -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("bind the mock server"));
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let thread = {
+            let server = Arc::clone(&server);
+            let state = Arc::clone(&state);
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let mut body = String::new();
+                    let _ = request.as_reader().read_to_string(&mut body);
+                    let recorded = Recorded {
+                        path: request.url().to_string(),
+                        body: serde_json::from_str(&body).unwrap_or(Value::Null),
+                    };
+
+                    let (status, answer) = {
+                        let mut state = state.lock().unwrap();
+                        let answer = state.answers.get(recorded.method()).cloned();
+                        state.requests.push(recorded);
+                        answer.unwrap_or((200, json!({ "ok": true, "result": true })))
+                    };
+                    let content_type =
+                        Header::from_bytes("Content-Type", "application/json").unwrap();
+                    let response = Response::from_string(answer.to_string())
+                        .with_status_code(status)
+                        .with_header(content_type);
+                    let _ = request.respond(response);
+                }
+            })
+        };
+
+        Self {
+            server,
+            state,
+            thread: Some(thread),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        let address = self.server.server_addr().to_ip().expect("a TCP address");
+        format!("http://{address}")
+    }
+
+    /// Answers every later call of `method` with `body`.
+    pub fn answer(&self, method: &str, body: Value) {
+        self.answer_with_status(method, 200, body);
+    }
+
+    pub fn answer_with_status(&self, method: &str, status: u16, body: Value) {
+        let mut state = self.state.lock().unwrap();
+        state.answers.insert(method.to_string(), (status, body));
+    }
+
+    /// Every call so far, oldest first.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
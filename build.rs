@@ -1,5 +1,6 @@
 use serde::Deserialize;
-use std::collections::HashMap;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::error::Error;
 use std::fs::{self, File};
@@ -8,44 +9,131 @@ use std::path::{Path, PathBuf};
 
 const LOWERCASE_WORDS: &[&str] = &["of", "the", "and", "in"];
 
+/// Minor Arcana suits in traditional deck order, as they appear in slugs,
+/// with the element that rules them.
+const SUITS: &[(&str, &str, &str)] = &[
+    ("wands", "Wands", "Fire"),
+    ("cups", "Cups", "Water"),
+    ("swords", "Swords", "Air"),
+    ("pentacles", "Pentacles", "Earth"),
+];
+
+/// Elements as written in `cards_data.json`, with their `Element` variant.
+const ELEMENTS: &[(&str, &str)] = &[
+    ("fire", "Fire"),
+    ("water", "Water"),
+    ("air", "Air"),
+    ("earth", "Earth"),
+];
+
+/// Minor Arcana ranks from Ace to King, as they appear in slugs.
+const RANKS: &[(&str, &str)] = &[
+    ("ace", "Ace"),
+    ("two", "Two"),
+    ("three", "Three"),
+    ("four", "Four"),
+    ("five", "Five"),
+    ("six", "Six"),
+    ("seven", "Seven"),
+    ("eight", "Eight"),
+    ("nine", "Nine"),
+    ("ten", "Ten"),
+    ("page", "Page"),
+    ("knight", "Knight"),
+    ("queen", "Queen"),
+    ("king", "King"),
+];
+
+/// Yes/No answers as written in `cards_data.json`, with their `Answer` variant.
+const ANSWERS: &[(&str, &str)] = &[("yes", "Yes"), ("no", "No"), ("maybe", "Maybe")];
+
+/// Number of Major Arcana cards; the Minor Arcana indices start right after.
+const MAJOR_COUNT: usize = 22;
+
+/// Cards in a complete deck: 22 Major and 56 Minor Arcana.
+const DECK_SIZE: usize = 78;
+
+/// Language whose `translations/<lang>.json` defines the keys every other
+/// language is checked against and falls back to.
+const REFERENCE_LANGUAGE: &str = "en";
+
+/// Struct in `src/i18n.rs` generated for each object of a translation file,
+/// by its dotted path; `*` stands for any key of a map.
+const TRANSLATION_TYPES: &[(&str, &str)] = &[
+    ("", "Translations"),
+    ("buttons", "ButtonTranslations"),
+    ("spreads.*", "SpreadOption"),
+    ("positions.*", "PositionTranslation"),
+    ("reversals", "ReversalTranslations"),
+    ("deck", "DeckTranslations"),
+    ("deck.suits", "SuitTranslations"),
+    ("significator", "SignificatorTranslations"),
+    ("significator.ranks", "CourtRankTranslations"),
+    ("empty", "EmptyTranslations"),
+    ("reading", "ReadingTranslations"),
+    ("insights", "InsightTranslations"),
+    ("insights.ranks", "PipRankTranslations"),
+    ("elements", "ElementTranslations"),
+    ("yes_no", "YesNoTranslations"),
+    ("history", "HistoryTranslations"),
+    ("feedback", "FeedbackTranslations"),
+    ("errors", "ErrorTranslations"),
+    ("orientation", "OrientationTranslations"),
+];
+
+/// Plural rules as written in `locales.json`, with their `PluralRules` variant.
+const PLURAL_RULES: &[(&str, &str)] = &[
+    ("none", "None"),
+    ("one_other", "OneOther"),
+    ("french", "French"),
+    ("east_slavic", "EastSlavic"),
+    ("polish", "Polish"),
+    ("czech", "Czech"),
+    ("hebrew", "Hebrew"),
+    ("arabic", "Arabic"),
+];
+
+/// Objects keyed by spread or position id rather than by field; they become
+/// `&[(id, value)]` slices.
+const TRANSLATION_MAPS: &[&str] = &["spreads", "positions"];
+
 fn main() -> Result<(), Box<dyn Error>> {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=assets");
 
     let metadata = load_metadata()?;
+    let images = discover_images();
+    let cards = build_deck(&images, &metadata)?;
 
     let out_dir = PathBuf::from(env::var("OUT_DIR")?);
     let dest_path = out_dir.join("generated_cards.rs");
     let mut output = File::create(dest_path)?;
-
-    let cards = discover_cards(&metadata)?;
     write_cards(&mut output, &cards)?;
 
+    let combinations = load_combinations(&cards)?;
+    let mut output = File::create(out_dir.join("generated_combinations.rs"))?;
+    write_combinations(&mut output, &combinations)?;
+
+    let mut output = File::create(out_dir.join("generated_translations.rs"))?;
+    write_translations(&mut output, &cards, &combinations)?;
+
     Ok(())
 }
 
-fn load_metadata() -> Result<HashMap<String, CardMetadata>, Box<dyn Error>> {
+fn load_metadata() -> Result<Vec<CardMetadata>, Box<dyn Error>> {
     let path = Path::new("cards_data.json");
     if !path.exists() {
-        return Ok(HashMap::new());
+        return Ok(Vec::new());
     }
 
     println!("cargo:rerun-if-changed=cards_data.json");
     let contents = fs::read_to_string(path)?;
-    let entries: Vec<CardMetadata> = serde_json::from_str(&contents)?;
-
-    let mut map = HashMap::new();
-    for entry in entries {
-        map.insert(entry.slug.clone(), entry);
-    }
-
-    Ok(map)
+    Ok(serde_json::from_str(&contents)?)
 }
 
-fn discover_cards(
-    metadata: &HashMap<String, CardMetadata>,
-) -> Result<Vec<CardDescriptor>, Box<dyn Error>> {
-    let mut cards = Vec::new();
+/// `(slug, display name from the filename)` for every `.webp` in `assets/`.
+fn discover_images() -> Vec<(String, String)> {
+    let mut images = Vec::new();
 
     if let Ok(entries) = fs::read_dir("assets") {
         for entry in entries.flatten() {
@@ -56,76 +144,834 @@ fn discover_cards(
 
             if let Some(filename) = path.file_name().and_then(|name| name.to_str()) {
                 println!("cargo:rerun-if-changed=assets/{filename}");
-                if let Some((slug, fallback_name)) = parse_filename(filename) {
-                    cards.push(build_descriptor(slug, fallback_name, metadata));
-                }
+                images.extend(parse_filename(filename));
             }
         }
     }
 
-    cards.sort_by(|a, b| a.slug.cmp(&b.slug));
-    cards.dedup_by(|a, b| a.slug == b.slug);
-    Ok(cards)
+    images.sort();
+    images
+}
+
+/// Turns every image into a card and checks the deck as a whole: each image
+/// needs complete metadata, each metadata entry needs an image, nothing may
+/// appear twice, and the deck must hold exactly 78 cards. Every problem is
+/// collected so one build reports all of them.
+fn build_deck(
+    images: &[(String, String)],
+    metadata: &[CardMetadata],
+) -> Result<Vec<CardDescriptor>, Box<dyn Error>> {
+    let mut problems = Vec::new();
+    let has_image = |slug: &str| images.iter().any(|(image, _)| image == slug);
+
+    let mut by_slug: HashMap<&str, &CardMetadata> = HashMap::new();
+    for entry in metadata {
+        if by_slug.insert(&entry.slug, entry).is_some() {
+            problems.push(format!(
+                "`{}` appears more than once in cards_data.json",
+                entry.slug
+            ));
+        }
+        if !has_image(&entry.slug) {
+            problems.push(format!(
+                "`{}` is in cards_data.json but assets/{}.webp is missing",
+                entry.slug, entry.slug
+            ));
+        }
+    }
+
+    let mut cards: Vec<CardDescriptor> = Vec::new();
+    for (slug, fallback_name) in images {
+        if cards.iter().any(|card| card.slug == *slug) {
+            problems.push(format!("more than one image in assets/ is named `{slug}`"));
+            continue;
+        }
+        let Some(meta) = by_slug.get(slug.as_str()) else {
+            problems.push(format!(
+                "assets/{slug}.webp has no entry in cards_data.json"
+            ));
+            continue;
+        };
+        match build_descriptor(slug, fallback_name, meta) {
+            Ok(card) => cards.push(card),
+            Err(err) => problems.push(err.to_string()),
+        }
+    }
+
+    cards.sort_by(|a, b| a.index.cmp(&b.index).then_with(|| a.slug.cmp(&b.slug)));
+    for pair in cards.windows(2) {
+        if pair[0].index == pair[1].index {
+            problems.push(format!(
+                "`{}` and `{}` both claim deck position {}",
+                pair[0].slug, pair[1].slug, pair[0].index
+            ));
+        }
+    }
+
+    if problems.is_empty() && cards.len() != DECK_SIZE {
+        problems.push(format!(
+            "the deck has {} cards, but a tarot deck has {DECK_SIZE}",
+            cards.len()
+        ));
+    }
+
+    if problems.is_empty() {
+        return Ok(cards);
+    }
+
+    // Cargo shows a failed build script's stderr, one problem per line.
+    for problem in &problems {
+        eprintln!("invalid card data: {problem}");
+    }
+    Err(format!(
+        "{} problem(s) in the card data, listed above",
+        problems.len()
+    )
+    .into())
 }
 
 fn build_descriptor(
-    slug: String,
-    fallback_name: String,
-    metadata: &HashMap<String, CardMetadata>,
-) -> CardDescriptor {
-    if let Some(meta) = metadata.get(&slug) {
-        let name = meta.name.clone().unwrap_or_else(|| fallback_name.clone());
-        let upright = meta
-            .upright
-            .clone()
-            .unwrap_or_else(|| default_upright(&name));
-        let reversed = meta
-            .reversed
+    slug: &str,
+    fallback_name: &str,
+    meta: &CardMetadata,
+) -> Result<CardDescriptor, Box<dyn Error>> {
+    let (index, arcana, suit_element) = classify(slug, meta.number)?;
+    let element = resolve_element(slug, suit_element, meta.element.as_deref())?;
+    let missing = |field: &str| format!("`{slug}` has no `{field}` in cards_data.json");
+
+    let keywords = meta.keywords.clone().unwrap_or_default();
+    if keywords.is_empty() {
+        return Err(missing("keywords").into());
+    }
+
+    Ok(CardDescriptor {
+        slug: slug.to_string(),
+        display_name: meta
+            .name
             .clone()
-            .unwrap_or_else(|| default_reversed(&name));
-        let keywords = meta.keywords.clone().unwrap_or_default();
+            .unwrap_or_else(|| fallback_name.to_string()),
+        index,
+        arcana,
+        element,
+        astrology: meta.astrology.clone(),
+        yes_no: resolve_yes_no(slug, meta.yes_no.as_ref().ok_or_else(|| missing("yes_no"))?)?,
+        upright: meta.upright.clone().ok_or_else(|| missing("upright"))?,
+        reversed: meta.reversed.clone().ok_or_else(|| missing("reversed"))?,
+        keywords,
+    })
+}
+
+/// Compiles the texts of every language in `translations/` into statics
+/// named `TRANSLATIONS_<LANG>`, `CARD_TRANSLATIONS_<LANG>` and
+/// `COVERAGE_<LANG>`, and lists the languages in the `LOCALES` registry.
+///
+/// English is the schema: its keys decide the fields, so a key the Rust types
+/// do not know fails the build. Every text is then resolved on its own along
+/// the language's fallback chain (see [`fallback_chain`]), which lets a partial
+/// translation ship; the keys that fell back end up in the coverage table.
+fn write_translations(
+    writer: &mut File,
+    cards: &[CardDescriptor],
+    combinations: &[CombinationEntry],
+) -> Result<(), Box<dyn Error>> {
+    let locales = load_locales()?;
+    let languages = translation_languages()?;
+    if !languages.iter().any(|code| code == REFERENCE_LANGUAGE) {
+        return Err(format!(
+            "{} is missing",
+            translation_path(REFERENCE_LANGUAGE).display()
+        )
+        .into());
+    }
+
+    let verbose = env::var("PROFILE").is_ok_and(|profile| profile == "debug");
+    // Pseudo-locales are made up from English and only ship in debug builds.
+    let pseudo: Vec<String> = locales
+        .iter()
+        .filter(|(code, locale)| locale.pseudo && verbose && !languages.contains(code))
+        .map(|(code, _)| code.clone())
+        .collect();
+
+    let mut texts = HashMap::new();
+    let mut card_texts = HashMap::new();
+    for code in &languages {
+        texts.insert(code.as_str(), read_json(&translation_path(code))?);
+        card_texts.insert(code.as_str(), load_card_translations(code, cards)?);
+    }
+    for code in &pseudo {
+        let text = pseudo_rtl_value(&texts[REFERENCE_LANGUAGE]);
+        let card_text = pseudo_rtl_cards(cards, card_texts[REFERENCE_LANGUAGE].as_ref());
+        texts.insert(code.as_str(), text);
+        card_texts.insert(code.as_str(), Some(card_text));
+    }
+    let reference = &texts[REFERENCE_LANGUAGE];
+    for (code, locale) in &locales {
+        if !locale.pseudo && !languages.contains(code) {
+            println!(
+                "cargo:warning=locales.json lists `{code}`, but {} is missing",
+                translation_path(code).display()
+            );
+        }
+    }
+    let mut registry = Vec::new();
+
+    for code in languages.iter().chain(&pseudo) {
+        let chain = fallback_chain(code, &locales, &languages)?;
+        let mut coverage = Coverage::new(code);
+
+        let layers: Vec<_> = chain
+            .iter()
+            .map(|code| (code.as_str(), texts.get(code.as_str())))
+            .collect();
+        let plural = plural_rules(code, &locales)?;
+        let literal = translation_literal(
+            &layers,
+            reference,
+            "",
+            "",
+            &[format!("plural: PluralRules::{plural}")],
+            &mut coverage,
+        )?;
+
+        let layers: Vec<_> = chain
+            .iter()
+            .map(|code| (code.as_str(), card_texts[code.as_str()].as_ref()))
+            .collect();
+        let card_literals: Vec<_> = cards
+            .iter()
+            .map(|card| card_translation_literal(card, &layers, &mut coverage))
+            .collect();
+
+        let combination_literals: Vec<_> = combinations
+            .iter()
+            .map(|combination| combination_literal(combination, &chain, &pseudo, &mut coverage))
+            .collect();
+
+        report_coverage(&coverage, verbose);
 
-        CardDescriptor {
-            slug,
-            display_name: name,
-            upright,
-            reversed,
-            keywords,
+        let name = static_suffix(code);
+        writeln!(
+            writer,
+            "pub static TRANSLATIONS_{name}: Translations = {literal};"
+        )?;
+        writeln!(
+            writer,
+            "pub static CARD_TRANSLATIONS_{name}: CardTranslations = CardTranslations(&["
+        )?;
+        for literal in card_literals {
+            writeln!(writer, "    {literal},")?;
+        }
+        writeln!(writer, "]);")?;
+        writeln!(
+            writer,
+            "pub static COMBINATION_TRANSLATIONS_{name}: CombinationTranslations = CombinationTranslations(&["
+        )?;
+        for literal in combination_literals {
+            writeln!(writer, "    {literal},")?;
         }
+        writeln!(writer, "]);")?;
+        writeln!(
+            writer,
+            "pub static COVERAGE_{name}: TranslationCoverage = {};",
+            coverage.literal()
+        )?;
+        registry.push(locale_literal(code, &name, &locales)?);
+    }
+
+    writeln!(writer, "pub static LOCALES: &[Locale] = &[")?;
+    for literal in registry {
+        writeln!(writer, "    {literal},")?;
+    }
+    writeln!(writer, "];")?;
+    Ok(())
+}
+
+/// Builds the `Locale` registry entry for a language from its metadata in
+/// `locales.json`. A language without an entry still works, under its code.
+fn locale_literal(
+    code: &str,
+    name: &str,
+    locales: &BTreeMap<String, LocaleConfig>,
+) -> Result<String, Box<dyn Error>> {
+    let Some(locale) = locales.get(code) else {
+        println!("cargo:warning=`{code}` has no entry in locales.json; it is listed by its code");
+        return Ok(format!(
+            "Locale {{ code: {code:?}, name: {code:?}, flag: \"\", direction: Direction::Ltr, aliases: &[], translations: &TRANSLATIONS_{name}, card_translations: &CARD_TRANSLATIONS_{name}, combination_translations: &COMBINATION_TRANSLATIONS_{name}, coverage: &COVERAGE_{name} }}"
+        ));
+    };
+
+    let direction = match locale.direction.as_deref() {
+        None | Some("ltr") => "Ltr",
+        Some("rtl") => "Rtl",
+        Some(other) => {
+            return Err(format!(
+                "locales.json: `{code}` has direction `{other}`; use `ltr` or `rtl`"
+            )
+            .into())
+        }
+    };
+    let aliases = locale
+        .aliases
+        .iter()
+        .map(|alias| format!("{:?}", alias.to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok(format!(
+        "Locale {{ code: {code:?}, name: {:?}, flag: {:?}, direction: Direction::{direction}, aliases: &[{aliases}], translations: &TRANSLATIONS_{name}, card_translations: &CARD_TRANSLATIONS_{name}, combination_translations: &COMBINATION_TRANSLATIONS_{name}, coverage: &COVERAGE_{name} }}",
+        locale.name.as_deref().unwrap_or(code),
+        locale.flag.as_deref().unwrap_or_default(),
+    ))
+}
+
+/// The part of a static's name that stands for a language, e.g. `PT_BR`.
+fn static_suffix(code: &str) -> String {
+    code.to_uppercase()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+}
+
+/// Reads `locales.json`: each language's name, flag, text direction, plural
+/// rules and fallback `parent`.
+fn load_locales() -> Result<BTreeMap<String, LocaleConfig>, Box<dyn Error>> {
+    let path = Path::new("locales.json");
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    Ok(serde_json::from_value(read_json(path)?)
+        .map_err(|err| format!("locales.json does not parse: {err}"))?)
+}
+
+/// The `PluralRules` variant for a language, from its `plural` in
+/// `locales.json`; languages without one count like English.
+fn plural_rules(
+    code: &str,
+    locales: &BTreeMap<String, LocaleConfig>,
+) -> Result<&'static str, Box<dyn Error>> {
+    let Some(key) = locales
+        .get(code)
+        .and_then(|locale| locale.plural.as_deref())
+    else {
+        return Ok("OneOther");
+    };
+    PLURAL_RULES
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, variant)| *variant)
+        .ok_or_else(|| format!("locales.json: `{code}` has unknown plural rules `{key}`").into())
+}
+
+/// Mirrors the English texts into a right-to-left pseudo-language, so the
+/// RTL layout can be checked without knowing Arabic or Hebrew.
+fn pseudo_rtl_value(value: &Value) -> Value {
+    match value {
+        Value::String(text) => Value::String(pseudo_rtl(text)),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.clone(), pseudo_rtl_value(value)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// The pseudo right-to-left text of every card, from its English text.
+fn pseudo_rtl_cards(
+    cards: &[CardDescriptor],
+    english: Option<&BTreeMap<String, CardTranslationEntry>>,
+) -> BTreeMap<String, CardTranslationEntry> {
+    cards
+        .iter()
+        .map(|card| {
+            let entry = english.and_then(|english| english.get(&card.slug));
+            let text = |get: fn(&CardTranslationEntry) -> Option<&String>, base: &String| {
+                pseudo_rtl(entry.and_then(get).unwrap_or(base))
+            };
+            let keywords = entry
+                .and_then(|entry| entry.keywords.as_ref())
+                .filter(|keywords| !keywords.is_empty())
+                .unwrap_or(&card.keywords);
+            let translation = CardTranslationEntry {
+                name: Some(text(|entry| entry.name.as_ref(), &card.display_name)),
+                astrology: entry
+                    .and_then(|entry| entry.astrology.as_ref())
+                    .or(card.astrology.as_ref())
+                    .map(|astrology| pseudo_rtl(astrology)),
+                upright: Some(text(|entry| entry.upright.as_ref(), &card.upright)),
+                reversed: Some(text(|entry| entry.reversed.as_ref(), &card.reversed)),
+                keywords: Some(keywords.iter().map(|keyword| pseudo_rtl(keyword)).collect()),
+            };
+            (card.slug.clone(), translation)
+        })
+        .collect()
+}
+
+/// Turns English into pseudo right-to-left text, as Android's `ar-XB` does:
+/// a right-to-left mark makes the text RTL, and every word is overridden to
+/// run right to left, so it reads mirrored. Message syntax is left alone:
+/// only text outside `{…}` arguments, or inside plural branches, changes.
+fn pseudo_rtl(message: &str) -> String {
+    fn flush(word: &mut String, out: &mut String) {
+        if !word.is_empty() {
+            out.push('\u{202E}');
+            out.push_str(word);
+            out.push('\u{202C}');
+            word.clear();
+        }
+    }
+
+    let mut out = String::from('\u{200F}');
+    let mut word = String::new();
+    // Even depths are text; odd ones are the inside of an argument.
+    let mut depth = 0usize;
+    for c in message.chars() {
+        if depth.is_multiple_of(2) && !matches!(c, '{' | '}' | '#') && !c.is_whitespace() {
+            word.push(c);
+            continue;
+        }
+        flush(&mut word, &mut out);
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        out.push(c);
+    }
+    flush(&mut word, &mut out);
+    out
+}
+
+/// Where a language looks for a text it lacks: the language itself, then its
+/// `parent` from `locales.json` (and the parent's parent), then English.
+fn fallback_chain(
+    code: &str,
+    locales: &BTreeMap<String, LocaleConfig>,
+    languages: &[String],
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut chain = vec![code.to_string()];
+    let mut current = code;
+    while let Some(parent) = locales
+        .get(current)
+        .and_then(|locale| locale.parent.as_deref())
+    {
+        if chain.iter().any(|seen| seen == parent) {
+            return Err(format!("locales.json: the parents of `{code}` form a loop").into());
+        }
+        if !languages.iter().any(|language| language == parent) {
+            return Err(format!(
+                "locales.json: `{current}` falls back to `{parent}`, which has no {}",
+                translation_path(parent).display()
+            )
+            .into());
+        }
+        chain.push(parent.to_string());
+        current = parent;
+    }
+    if !chain.iter().any(|language| language == REFERENCE_LANGUAGE) {
+        chain.push(REFERENCE_LANGUAGE.to_string());
+    }
+    Ok(chain)
+}
+
+/// Reads `translations/cards_<code>.json`, if the language has one. A file
+/// that does not parse fails the build; slugs that are not in the deck are
+/// reported and ignored.
+fn load_card_translations(
+    code: &str,
+    cards: &[CardDescriptor],
+) -> Result<Option<BTreeMap<String, CardTranslationEntry>>, Box<dyn Error>> {
+    let path = translation_path(&format!("cards_{code}"));
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    println!("cargo:rerun-if-changed={}", path.display());
+    let contents = fs::read_to_string(&path)?;
+    let translations: BTreeMap<String, CardTranslationEntry> = serde_json::from_str(&contents)
+        .map_err(|err| format!("{} does not parse: {err}", path.display()))?;
+
+    for slug in translations.keys() {
+        if !cards.iter().any(|card| card.slug == *slug) {
+            println!(
+                "cargo:warning={} translates `{slug}`, which is not in the deck",
+                path.display()
+            );
+        }
+    }
+    Ok(Some(translations))
+}
+
+/// Language codes of the UI files `translations/<code>.json`, sorted.
+fn translation_languages() -> Result<Vec<String>, Box<dyn Error>> {
+    let mut codes = Vec::new();
+    for entry in fs::read_dir("translations")? {
+        let path = entry?.path();
+        let code = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".json"));
+        if let Some(code) = code.filter(|code| !code.starts_with("cards_")) {
+            codes.push(code.to_string());
+        }
+    }
+    codes.sort();
+    Ok(codes)
+}
+
+fn translation_path(name: &str) -> PathBuf {
+    Path::new("translations").join(format!("{name}.json"))
+}
+
+fn read_json(path: &Path) -> Result<Value, Box<dyn Error>> {
+    println!("cargo:rerun-if-changed={}", path.display());
+    let contents =
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    Ok(serde_json::from_str(&contents)
+        .map_err(|err| format!("{} does not parse: {err}", path.display()))?)
+}
+
+/// How the texts of one language were resolved.
+struct Coverage {
+    language: String,
+    /// Texts the language ends up with, its own or not.
+    keys: usize,
+    /// `(key, language the text came from)` for every text that fell back.
+    fallbacks: Vec<(String, String)>,
+    /// Keys the language has and English lacks.
+    unknown: Vec<String>,
+}
+
+impl Coverage {
+    fn new(language: &str) -> Self {
+        Self {
+            language: language.to_string(),
+            keys: 0,
+            fallbacks: Vec::new(),
+            unknown: Vec::new(),
+        }
+    }
+
+    /// Notes that the text for `key` was taken from `source`.
+    fn record(&mut self, key: String, source: &str) {
+        self.keys += 1;
+        if source != self.language {
+            self.fallbacks.push((key, source.to_string()));
+        }
+    }
+
+    fn literal(&self) -> String {
+        let fallbacks = self
+            .fallbacks
+            .iter()
+            .map(|(key, source)| format!("({key:?}, {source:?})"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "TranslationCoverage {{ keys: {}, fallbacks: &[{fallbacks}] }}",
+            self.keys
+        )
+    }
+}
+
+/// Warns about unknown keys, and sums up what fell back. Debug builds list
+/// every fallen-back key as well.
+fn report_coverage(coverage: &Coverage, verbose: bool) {
+    let path = translation_path(&coverage.language);
+    for key in &coverage.unknown {
+        println!(
+            "cargo:warning={} has `{key}`, which English does not; it is ignored",
+            path.display()
+        );
+    }
+    if coverage.fallbacks.is_empty() {
+        return;
+    }
+
+    println!(
+        "cargo:warning=`{}` translates {} of {} texts; the rest fall back",
+        coverage.language,
+        coverage.keys - coverage.fallbacks.len(),
+        coverage.keys
+    );
+    if verbose {
+        for (key, source) in &coverage.fallbacks {
+            println!(
+                "cargo:warning=`{}` takes `{key}` from `{source}`",
+                coverage.language
+            );
+        }
+    }
+}
+
+/// Builds the Rust literal for the translation object at `path`. `reference`
+/// (English) gives the shape; each text comes from the first of `layers`, the
+/// values at `path` along the fallback chain, that has it. `type_path` is
+/// `path` with map keys replaced by `*`, as in [`TRANSLATION_TYPES`].
+fn translation_literal(
+    layers: &[(&str, Option<&Value>)],
+    reference: &Value,
+    path: &str,
+    type_path: &str,
+    extra_fields: &[String],
+    coverage: &mut Coverage,
+) -> Result<String, Box<dyn Error>> {
+    let fields = match reference {
+        Value::String(english) => {
+            let (source, text) = layers
+                .iter()
+                .find_map(|(code, value)| match value {
+                    Some(Value::String(text)) => Some((*code, text)),
+                    _ => None,
+                })
+                .unwrap_or((REFERENCE_LANGUAGE, english));
+            coverage.record(path.to_string(), source);
+            return Ok(format!("{text:?}"));
+        }
+        Value::Object(fields) => fields,
+        _ => {
+            return Err(format!(
+                "{}: `{path}` must be text or an object",
+                translation_path(REFERENCE_LANGUAGE).display()
+            )
+            .into())
+        }
+    };
+
+    let own = layers
+        .first()
+        .and_then(|(_, value)| value.and_then(Value::as_object));
+    for key in own.into_iter().flat_map(|own| own.keys()) {
+        if !fields.contains_key(key) {
+            coverage.unknown.push(join_key(path, key));
+        }
+    }
+
+    let is_map = TRANSLATION_MAPS.contains(&type_path);
+    let mut entries = Vec::with_capacity(fields.len());
+    for (key, reference) in fields {
+        let children: Vec<_> = layers
+            .iter()
+            .map(|(code, value)| (*code, value.and_then(|value| value.get(key))))
+            .collect();
+        let child_type = join_key(type_path, if is_map { "*" } else { key });
+        let literal = translation_literal(
+            &children,
+            reference,
+            &join_key(path, key),
+            &child_type,
+            &[],
+            coverage,
+        )?;
+        entries.push(if is_map {
+            format!("({key:?}, {literal})")
+        } else {
+            format!("{key}: {literal}")
+        });
+    }
+
+    if is_map {
+        return Ok(format!("&[{}]", entries.join(", ")));
+    }
+    entries.extend_from_slice(extra_fields);
+    let name = TRANSLATION_TYPES
+        .iter()
+        .find(|(key, _)| *key == type_path)
+        .map(|(_, name)| *name)
+        .ok_or_else(|| {
+            format!("no Rust type for the translation object `{path}`; add it to TRANSLATION_TYPES in build.rs")
+        })?;
+    Ok(format!("{name} {{ {} }}", entries.join(", ")))
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
     } else {
-        let name = fallback_name;
-        CardDescriptor {
-            slug,
-            display_name: name.clone(),
-            upright: default_upright(&name),
-            reversed: default_reversed(&name),
-            keywords: Vec::new(),
+        format!("{path}.{key}")
+    }
+}
+
+/// Builds the `CardTranslation` literal for `card`. Each field is taken from
+/// the first card file along the fallback chain that has it, and finally from
+/// the English text in `cards_data.json`.
+fn card_translation_literal(
+    card: &CardDescriptor,
+    layers: &[(&str, Option<&BTreeMap<String, CardTranslationEntry>>)],
+    coverage: &mut Coverage,
+) -> String {
+    let entries: Vec<_> = layers
+        .iter()
+        .map(|(code, file)| (*code, file.and_then(|file| file.get(&card.slug))))
+        .collect();
+    let mut field = |name: &str,
+                     get: &dyn Fn(&CardTranslationEntry) -> Option<&String>,
+                     base: Option<&String>| {
+        let found = entries
+            .iter()
+            .find_map(|(code, entry)| entry.and_then(get).map(|text| (*code, text.clone())))
+            .or_else(|| base.map(|text| (REFERENCE_LANGUAGE, text.clone())));
+        if let Some((source, _)) = &found {
+            coverage.record(format!("cards.{}.{name}", card.slug), source);
+        }
+        found.map(|(_, text)| text)
+    };
+
+    let name = field(
+        "name",
+        &|entry| entry.name.as_ref(),
+        Some(&card.display_name),
+    );
+    let astrology = field(
+        "astrology",
+        &|entry| entry.astrology.as_ref(),
+        card.astrology.as_ref(),
+    );
+    let upright = field(
+        "upright",
+        &|entry| entry.upright.as_ref(),
+        Some(&card.upright),
+    );
+    let reversed = field(
+        "reversed",
+        &|entry| entry.reversed.as_ref(),
+        Some(&card.reversed),
+    );
+
+    let keywords = entries
+        .iter()
+        .find_map(|(code, entry)| {
+            entry
+                .and_then(|entry| entry.keywords.as_ref())
+                .filter(|keywords| !keywords.is_empty())
+                .map(|keywords| (*code, keywords))
+        })
+        .unwrap_or((REFERENCE_LANGUAGE, &card.keywords));
+    coverage.record(format!("cards.{}.keywords", card.slug), keywords.0);
+    let keywords = keywords
+        .1
+        .iter()
+        .map(|keyword| format!("{keyword:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "CardTranslation {{ name: {:?}, astrology: {astrology:?}, upright: {:?}, reversed: {:?}, keywords: &[{keywords}] }}",
+        name.unwrap_or_default(),
+        upright.unwrap_or_default(),
+        reversed.unwrap_or_default(),
+    )
+}
+
+/// Works out the deck index and the `Arcana` literal for a card, plus the
+/// element of its suit for the Minor Arcana.
+///
+/// Minor Arcana are recognised from `<rank>-of-<suit>` slugs; everything else
+/// must carry its trump `number` in `cards_data.json`.
+fn classify(
+    slug: &str,
+    number: Option<u8>,
+) -> Result<(usize, String, Option<&'static str>), Box<dyn Error>> {
+    if let Some((rank, suit)) = slug.split_once("-of-") {
+        let rank = RANKS.iter().position(|(key, _)| *key == rank);
+        let suit = SUITS.iter().position(|(key, _, _)| *key == suit);
+        if let (Some(rank), Some(suit)) = (rank, suit) {
+            let index = MAJOR_COUNT + suit * RANKS.len() + rank;
+            let arcana = format!(
+                "Arcana::Minor {{ suit: Suit::{}, rank: Rank::{} }}",
+                SUITS[suit].1, RANKS[rank].1
+            );
+            return Ok((index, arcana, Some(SUITS[suit].2)));
         }
     }
+
+    match number {
+        Some(number) if usize::from(number) < MAJOR_COUNT => Ok((
+            usize::from(number),
+            format!("Arcana::Major {{ number: {number} }}"),
+            None,
+        )),
+        Some(number) => Err(format!(
+            "card `{slug}` has trump number {number}, but the Major Arcana only go up to {}",
+            MAJOR_COUNT - 1
+        )
+        .into()),
+        None => Err(format!(
+            "card `{slug}` is not a `<rank>-of-<suit>` slug and has no Major Arcana `number` in cards_data.json"
+        )
+        .into()),
+    }
+}
+
+/// Picks the `Element` variant for a card. Minor Arcana take the element of
+/// their suit; the Major Arcana need an explicit `element` in
+/// `cards_data.json`, and a declared element must agree with the suit.
+fn resolve_element(
+    slug: &str,
+    suit_element: Option<&'static str>,
+    declared: Option<&str>,
+) -> Result<&'static str, Box<dyn Error>> {
+    let declared = match declared {
+        Some(key) => Some(
+            ELEMENTS
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, variant)| *variant)
+                .ok_or_else(|| format!("card `{slug}` has unknown element `{key}`"))?,
+        ),
+        None => None,
+    };
+
+    match (suit_element, declared) {
+        (Some(suit), Some(declared)) if suit != declared => Err(format!(
+            "card `{slug}` is declared as {declared}, but its suit belongs to {suit}"
+        )
+        .into()),
+        (Some(element), _) | (None, Some(element)) => Ok(element),
+        (None, None) => {
+            Err(format!("Major Arcana card `{slug}` needs an `element` in cards_data.json").into())
+        }
+    }
+}
+
+/// Builds the `YesNo` literal for a card.
+fn resolve_yes_no(slug: &str, yes_no: &YesNoMetadata) -> Result<String, Box<dyn Error>> {
+    let variant = |answer: &str| -> Result<&'static str, Box<dyn Error>> {
+        ANSWERS
+            .iter()
+            .find(|(key, _)| *key == answer)
+            .map(|(_, variant)| *variant)
+            .ok_or_else(|| format!("card `{slug}` has unknown yes/no answer `{answer}`").into())
+    };
+
+    Ok(format!(
+        "YesNo {{ upright: Answer::{}, reversed: Answer::{} }}",
+        variant(&yes_no.upright)?,
+        variant(&yes_no.reversed)?
+    ))
 }
 
 fn write_cards(writer: &mut File, cards: &[CardDescriptor]) -> Result<(), Box<dyn Error>> {
-    writeln!(writer, "pub const AUTO_CARDS: &[TarotCard] = &[")?;
+    writeln!(writer, "pub static CARDS: &[TarotCard] = &[")?;
     for card in cards {
-        let keywords_literal = if card.keywords.is_empty() {
-            "AUTO_KEYWORDS".to_string()
-        } else {
-            let list = card
-                .keywords
-                .iter()
-                .map(|kw| format!("{kw:?}"))
-                .collect::<Vec<_>>()
-                .join(", ");
-            format!("&[{list}]")
-        };
+        let keywords_literal = card
+            .keywords
+            .iter()
+            .map(|kw| format!("{kw:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
 
         writeln!(
             writer,
-            "    TarotCard {{ slug: {slug:?}, name: {name:?}, upright: {upright:?}, reversed: {reversed:?}, keywords: {keywords} }},",
+            "    TarotCard {{ slug: {slug:?}, name: {name:?}, index: {index}, arcana: {arcana}, element: Element::{element}, astrology: {astrology:?}, upright: {upright:?}, reversed: {reversed:?}, yes_no: {yes_no}, keywords: &[{keywords}] }},",
             slug = card.slug,
             name = card.display_name,
+            index = card.index,
+            arcana = card.arcana,
+            element = card.element,
+            astrology = card.astrology,
             upright = card.upright,
             reversed = card.reversed,
+            yes_no = card.yes_no,
             keywords = keywords_literal,
         )?;
     }
@@ -133,6 +979,115 @@ fn write_cards(writer: &mut File, cards: &[CardDescriptor]) -> Result<(), Box<dy
     Ok(())
 }
 
+/// The combined meaning of one pair, from the first language along `chain`
+/// that has it. Pseudo-locales make theirs up from the English text.
+fn combination_literal(
+    combination: &CombinationEntry,
+    chain: &[String],
+    pseudo: &[String],
+    coverage: &mut Coverage,
+) -> String {
+    let [first, second] = &combination.cards;
+    let found = chain.iter().find_map(|code| {
+        let text = if pseudo.contains(code) {
+            combination
+                .text
+                .get(REFERENCE_LANGUAGE)
+                .map(|text| pseudo_rtl(text))
+        } else {
+            combination.text.get(code).cloned()
+        };
+        text.map(|text| (code, text))
+    });
+    // `load_combinations` made sure English, the end of every chain, has it.
+    let (source, text) = found.expect("combination without English text");
+    coverage.record(
+        format!("combinations.{}+{}", first.slug, second.slug),
+        source,
+    );
+    format!("{text:?}")
+}
+
+/// Reads `combinations.json` and checks it against the discovered deck: every
+/// slug must exist, orientations must be `upright` or `reversed`, and each
+/// entry needs English text as the fallback for other languages.
+fn load_combinations(cards: &[CardDescriptor]) -> Result<Vec<CombinationEntry>, Box<dyn Error>> {
+    let path = Path::new("combinations.json");
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    println!("cargo:rerun-if-changed=combinations.json");
+    let contents = fs::read_to_string(path)?;
+    let entries: Vec<CombinationEntry> = serde_json::from_str(&contents)?;
+
+    for entry in &entries {
+        let [first, second] = &entry.cards;
+        for card in &entry.cards {
+            if !cards.iter().any(|known| known.slug == card.slug) {
+                return Err(format!(
+                    "combinations.json refers to `{}`, which is not in the deck",
+                    card.slug
+                )
+                .into());
+            }
+            if let Some(orientation) = card.orientation.as_deref() {
+                if orientation != "upright" && orientation != "reversed" {
+                    return Err(format!(
+                        "combinations.json gives `{}` the unknown orientation `{orientation}`",
+                        card.slug
+                    )
+                    .into());
+                }
+            }
+        }
+        if first.slug == second.slug {
+            return Err(format!("combinations.json pairs `{}` with itself", first.slug).into());
+        }
+        if !entry.text.contains_key("en") {
+            return Err(format!(
+                "combination `{}` + `{}` has no English text",
+                first.slug, second.slug
+            )
+            .into());
+        }
+    }
+
+    Ok(entries)
+}
+
+fn write_combinations(
+    writer: &mut File,
+    combinations: &[CombinationEntry],
+) -> Result<(), Box<dyn Error>> {
+    writeln!(writer, "pub static COMBINATIONS: &[Combination] = &[")?;
+    for (index, combination) in combinations.iter().enumerate() {
+        let cards = combination
+            .cards
+            .iter()
+            .map(|card| {
+                let orientation = match card.orientation.as_deref() {
+                    Some("upright") => "Some(Orientation::Upright)",
+                    Some("reversed") => "Some(Orientation::Reversed)",
+                    _ => "None",
+                };
+                format!(
+                    "CardMatch {{ slug: {slug:?}, orientation: {orientation} }}",
+                    slug = card.slug
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(
+            writer,
+            "    Combination {{ cards: [{cards}], index: {index} }},"
+        )?;
+    }
+    writeln!(writer, "];")?;
+    Ok(())
+}
+
 fn parse_filename(filename: &str) -> Option<(String, String)> {
     let stem = filename.split('.').next()?.trim();
     if stem.is_empty() {
@@ -148,7 +1103,7 @@ fn prettify_card_slug(slug: &str) -> Option<String> {
         .trim_start_matches("_images_")
         .trim_start_matches("tarot_");
     let parts: Vec<String> = trimmed
-        .split(|c| c == '-' || c == '_')
+        .split(['-', '_'])
         .filter(|segment| !segment.is_empty())
         .map(|segment| segment.to_lowercase())
         .collect();
@@ -170,7 +1125,7 @@ fn prettify_card_slug(slug: &str) -> Option<String> {
 }
 
 fn fallback_display_name(slug: &str) -> String {
-    let cleaned = slug.replace('-', " ").replace('_', " ");
+    let cleaned = slug.replace(['-', '_'], " ");
     title_case(&cleaned)
 }
 
@@ -191,23 +1146,14 @@ fn is_webp(path: &Path) -> bool {
     )
 }
 
-fn default_upright(name: &str) -> String {
-    format!(
-        "{name} invites you to interpret this pull in the context of your question.",
-        name = name
-    )
-}
-
-fn default_reversed(name: &str) -> String {
-    format!(
-        "{name} reversed highlights the hidden dynamics around your question.",
-        name = name
-    )
-}
-
 struct CardDescriptor {
     slug: String,
     display_name: String,
+    index: usize,
+    arcana: String,
+    element: &'static str,
+    astrology: Option<String>,
+    yes_no: String,
     upright: String,
     reversed: String,
     keywords: Vec<String>,
@@ -217,7 +1163,70 @@ struct CardDescriptor {
 struct CardMetadata {
     slug: String,
     name: Option<String>,
+    /// Trump number for Major Arcana cards (0 = The Fool).
+    number: Option<u8>,
+    /// `fire`, `water`, `air` or `earth`; required for the Major Arcana only.
+    element: Option<String>,
+    /// Planet, sign or decan (e.g. "Mars in Aries") in the Golden Dawn system.
+    astrology: Option<String>,
+    upright: Option<String>,
+    reversed: Option<String>,
+    keywords: Option<Vec<String>>,
+    /// `yes`, `no` or `maybe` for each orientation, used by the Yes/No oracle.
+    yes_no: Option<YesNoMetadata>,
+}
+
+/// One card of `translations/cards_<lang>.json`. Every field is optional;
+/// a missing one falls back along the language's chain.
+#[derive(Debug, Deserialize)]
+struct CardTranslationEntry {
+    name: Option<String>,
+    astrology: Option<String>,
     upright: Option<String>,
     reversed: Option<String>,
     keywords: Option<Vec<String>>,
 }
+
+/// One language in `locales.json`.
+#[derive(Debug, Deserialize)]
+struct LocaleConfig {
+    /// Name of the language in itself, e.g. "Українська".
+    name: Option<String>,
+    /// Flag emoji shown next to the name.
+    flag: Option<String>,
+    /// `ltr` (the default) or `rtl`.
+    direction: Option<String>,
+    /// Other codes that select this language, e.g. `uk` for `ua`.
+    #[serde(default)]
+    aliases: Vec<String>,
+    /// Language to fall back to before English, e.g. `de` for `de-at`.
+    parent: Option<String>,
+    /// CLDR plural rules, one of [`PLURAL_RULES`].
+    plural: Option<String>,
+    /// A made-up locale generated from English for testing, e.g. `ar-XB`
+    /// for right-to-left. It needs no translation file and is left out of
+    /// release builds.
+    #[serde(default)]
+    pseudo: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct YesNoMetadata {
+    upright: String,
+    reversed: String,
+}
+
+/// One entry of `combinations.json`: an unordered pair of cards and the
+/// combined meaning per language code.
+#[derive(Debug, Deserialize)]
+struct CombinationEntry {
+    cards: [CombinationCard; 2],
+    text: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CombinationCard {
+    slug: String,
+    /// `upright` or `reversed`; matches either orientation when absent.
+    orientation: Option<String>,
+}
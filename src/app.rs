@@ -1,48 +1,143 @@
+use yew::platform::spawn_local;
 use yew::prelude::*;
 
-use crate::deck::{Deck, DrawCount};
-use crate::feedback::Feedback;
-use crate::i18n::{format_copied_message, CardTranslations, Language, Translations};
+use crate::backend;
+use crate::daily::{daily_seed, LocalDate};
+use crate::deck::{
+    Deck, DeckFilter, ReversalPolicy, Spread, TarotCard, DAILY_SPREAD, SIGNIFICATOR_POSITION,
+};
+use crate::feedback::{Feedback, Message};
+use crate::history::{self, HistoryEntry, SaveQueue, StorageError};
+use crate::i18n::{CardTranslations, Language, Translations};
+use crate::oracle::Verdict;
 use crate::reading::Reading;
 use crate::telegram::{
-    copy_to_clipboard, detect_language, init_web_app, theme_style, use_back_button,
-    BackButtonState, TelegramSetup,
+    can_send_data, copy_to_clipboard, detect_language, init_web_app, send_reading,
+    telegram_user_id, theme_style, use_back_button, use_main_button, BackButtonState,
+    BrowserStorage, MainButtonState, TelegramSetup,
+};
+use crate::ui::{
+    CardGrid, CombinationsPanel, DrawControls, HistoryPanel, InsightsPanel, SignificatorPicker,
+    StatusBanner, VerdictPanel, YesNoControls,
 };
-use crate::ui::{CardGrid, DrawControls, StatusBanner};
 
 #[function_component(App)]
 pub fn app() -> Html {
-    let draw_count = use_state(|| DrawCount::One);
+    let spread = use_state(Spread::default_spread);
+    let reversals = use_state(ReversalPolicy::default);
+    let deck_filter = use_state(DeckFilter::default);
+    let significator = use_state(|| None::<&'static TarotCard>);
+    let question = use_state(String::new);
     let reading = use_state(Reading::default);
     let feedback = use_state(Feedback::default);
     let telegram = use_state(TelegramSetup::default);
     let language = use_state(Language::default);
     let controls_collapsed = use_state(|| false);
+    let storage = use_state(|| None::<BrowserStorage>);
+    // Whether the newest reading is still being written to the history.
+    let saving = use_state(|| false);
+    // The history screen's entries while it is open.
+    let past_readings = use_state(|| None::<Vec<HistoryEntry>>);
+    // The entry the current reading was last saved as: its seed, how many
+    // clarifiers it had, and its `saved_at`.
+    let saved_as = use_mut_ref(|| None::<(u64, usize, u64)>);
+    let saves = use_memo((), |_| SaveQueue::default());
 
     // Derive translations from current language
-    let translations: Translations = language.load_translations();
-    let card_translations: CardTranslations = language.load_card_translations();
+    let translations: &'static Translations = language.translations();
+    let card_translations: &'static CardTranslations = language.card_translations();
 
     {
         let telegram = telegram.clone();
         let language = language.clone();
+        let storage = storage.clone();
         use_effect_with((), move |_| {
             telegram.set(init_web_app());
             language.set(detect_language());
+            storage.set(BrowserStorage::detect());
+            || ()
+        });
+    }
+
+    // Every fresh deal is saved, and saved again under the same entry when a
+    // clarifier is drawn. A reopened reading is saved already, as it is.
+    {
+        let reading = reading.clone();
+        let feedback = feedback.clone();
+        let saving = saving.clone();
+        let saved_as = saved_as.clone();
+        let saves = saves.clone();
+        let locale = language.code();
+        let key = (reading.seed(), reading.clarifiers().len(), *storage);
+        use_effect_with(key, move |&(seed, clarifiers, storage)| {
+            let saved_at = match *saved_as.borrow() {
+                Some((saved, count, _)) if Some(saved) == seed && count == clarifiers => None,
+                Some((saved, _, saved_at)) if Some(saved) == seed => Some(saved_at),
+                _ => Some(history::now_millis()),
+            };
+            let entry = saved_at.and_then(|saved_at| reading.history_entry(locale, saved_at));
+            if let (Some(storage), Some(entry)) = (storage, entry) {
+                *saved_as.borrow_mut() = Some((entry.seed, clarifiers, entry.saved_at));
+                saving.set(true);
+                spawn_local(async move {
+                    if let Err(err) = saves.save(&storage, entry).await {
+                        feedback.set(Feedback::error(err));
+                    }
+                    saving.set(saves.is_busy());
+                });
+            }
             || ()
         });
     }
 
+    // Dev builds list the texts the current language borrows from another one.
+    use_effect_with(*language, |language| {
+        let coverage = language.coverage();
+        if cfg!(debug_assertions) && !coverage.fallbacks.is_empty() {
+            gloo::console::warn!(format!(
+                "{}: {} of {} texts fall back",
+                language.code(),
+                coverage.fallbacks.len(),
+                coverage.keys
+            ));
+            for (key, source) in coverage.fallbacks {
+                gloo::console::warn!(format!(
+                    "{}: `{key}` comes from `{source}`",
+                    language.code()
+                ));
+            }
+        }
+        || ()
+    });
+
     let handle_select = {
-        let draw_count = draw_count.clone();
-        Callback::from(move |count: DrawCount| draw_count.set(count))
+        let spread = spread.clone();
+        Callback::from(move |selected: &'static Spread| spread.set(selected))
+    };
+
+    let handle_reversals_select = {
+        let reversals = reversals.clone();
+        Callback::from(move |policy: ReversalPolicy| reversals.set(policy))
+    };
+
+    let handle_filter_change = {
+        let deck_filter = deck_filter.clone();
+        Callback::from(move |filter: DeckFilter| deck_filter.set(filter))
+    };
+
+    let handle_significator_change = {
+        let significator = significator.clone();
+        Callback::from(move |card: Option<&'static TarotCard>| significator.set(card))
+    };
+
+    let handle_question_change = {
+        let question = question.clone();
+        Callback::from(move |text: String| question.set(text))
     };
 
-    let handle_language_toggle = {
+    let handle_language_change = {
         let language = language.clone();
-        Callback::from(move |_| {
-            language.set(language.toggle());
-        })
+        Callback::from(move |selected: Language| language.set(selected))
     };
 
     let handle_controls_toggle = {
@@ -53,18 +148,100 @@ pub fn app() -> Html {
     };
 
     let handle_draw = {
-        let draw_count = draw_count.clone();
+        let spread = spread.clone();
+        let reversals = reversals.clone();
+        let deck_filter = deck_filter.clone();
+        let significator = significator.clone();
+        let reading = reading.clone();
+        let feedback = feedback.clone();
+        Callback::from(move |_| {
+            // The significator is on the table already, so it cannot be drawn.
+            let chosen = (*deck_filter).clone();
+            let mut filter = chosen.clone();
+            filter.exclude.extend(significator.map(|card| card.slug));
+            let deck = Deck::standard().filter(&filter);
+            let seed = Deck::random_seed();
+            let significator = *significator;
+            deal_reading(
+                deck,
+                *spread,
+                *reversals,
+                seed,
+                |dealt| dealt.with_filter(chosen).with_significator(significator),
+                &reading,
+                &feedback,
+            );
+        })
+    };
+
+    let handle_daily = {
+        let telegram = telegram.clone();
+        let reading = reading.clone();
+        let feedback = feedback.clone();
+        Callback::from(move |_| {
+            // The full deck keeps the card independent of the current filter;
+            // the date is re-read on every press so it turns over at local midnight.
+            let date = LocalDate::today();
+            let seed = daily_seed(telegram_user_id().unwrap_or(0), date);
+            let reading = reading.clone();
+            let feedback = feedback.clone();
+            // `DAILY_SPREAD` pins its reversals, so the setting is not passed.
+            let deal = move |seed| {
+                deal_reading(
+                    Deck::standard(),
+                    &DAILY_SPREAD,
+                    ReversalPolicy::default(),
+                    seed,
+                    |dealt| dealt,
+                    &reading,
+                    &feedback,
+                )
+            };
+            if !(telegram.available && backend::is_configured()) {
+                deal(seed);
+                return;
+            }
+            // The backend seeds the card from the verified user id. It seeds
+            // it the same way, so the local seed stands in when it cannot answer.
+            spawn_local(async move {
+                deal(backend::daily_seed(date).await.unwrap_or(seed));
+            });
+        })
+    };
+
+    let handle_yes_no = {
+        let question = question.clone();
+        let reversals = reversals.clone();
         let reading = reading.clone();
         let feedback = feedback.clone();
-        Callback::from(move |_| match Deck::standard().draw_random(*draw_count) {
-            Ok(cards) => {
-                reading.set(Reading::from_cards(cards));
-                feedback.set(Feedback::default());
-                // Controls stay visible - user can collapse manually
+        Callback::from(move |spread: &'static Spread| {
+            let asked = question.trim().to_string();
+            if asked.is_empty() {
+                feedback.set(Feedback::error(Message::AskFirst));
+                return;
             }
-            Err(err) => {
-                reading.set(Reading::empty());
-                feedback.set(Feedback::error(err.to_string()));
+            // Like the daily card, the oracle always draws from the full deck.
+            let seed = Deck::random_seed();
+            deal_reading(
+                Deck::standard(),
+                spread,
+                *reversals,
+                seed,
+                |dealt| dealt.with_question(asked),
+                &reading,
+                &feedback,
+            );
+        })
+    };
+
+    let handle_clarify = {
+        let reading = reading.clone();
+        let feedback = feedback.clone();
+        Callback::from(move |parent: usize| {
+            let mut next = (*reading).clone();
+            match next.draw_clarifier(parent) {
+                Ok(()) => reading.set(next),
+                Err(err) => feedback.set(Feedback::error(err)),
             }
         })
     };
@@ -72,11 +249,10 @@ pub fn app() -> Html {
     let handle_reset = {
         let reading = reading.clone();
         let feedback = feedback.clone();
-        let t = translations.clone();
         let controls_collapsed = controls_collapsed.clone();
         Callback::from(move |_| {
             reading.set(Reading::empty());
-            feedback.set(Feedback::status(t.feedback.cleared.clone()));
+            feedback.set(Feedback::status(Message::Cleared));
             controls_collapsed.set(false); // Expand controls on reset
         })
     };
@@ -84,76 +260,276 @@ pub fn app() -> Html {
     let handle_copy = {
         let reading = reading.clone();
         let feedback = feedback.clone();
-        let t = translations.clone();
-        let ct = card_translations.clone();
+        let t = translations;
+        let ct = card_translations;
         Callback::from(move |_| {
             let cards = reading.cards();
             if cards.is_empty() {
-                feedback.set(Feedback::error(t.feedback.draw_first.clone()));
+                feedback.set(Feedback::error(Message::DrawFirst));
                 return;
             }
 
-            // Build translated full names
-            let names: Vec<String> = cards
-                .iter()
-                .map(|card| {
-                    let card_name = ct
-                        .get(card.card.slug)
-                        .and_then(|c| c.name.clone())
-                        .unwrap_or_else(|| card.card.name.to_string());
-
-                    match card.orientation {
-                        crate::deck::Orientation::Upright => card_name,
-                        crate::deck::Orientation::Reversed => {
-                            format!("{} ({})", card_name, &t.orientation.reversed)
-                        }
+            // Build translated full names, prefixed by position for multi-card spreads
+            let full_name = |card: &crate::deck::DrawnCard| {
+                let card_name = ct.get(card.card).name.to_string();
+
+                match card.orientation {
+                    crate::deck::Orientation::Upright => card_name,
+                    crate::deck::Orientation::Reversed => {
+                        format!("{} ({})", card_name, &t.orientation.reversed)
                     }
-                })
-                .collect();
+                }
+            };
+
+            let labelled = cards.len() > 1;
+            let mut names: Vec<String> = Vec::new();
+            if let Some(question) = reading.question() {
+                names.push(format!("{}: {question}", &t.yes_no.question));
+            }
+            if let Some(verdict) = Verdict::of_reading(&reading) {
+                names.push(format!(
+                    "{}: {}",
+                    &t.yes_no.verdict,
+                    t.answer_name(verdict.answer)
+                ));
+            }
+            if let Some(card) = reading.significator() {
+                let (position, _) = t.position_text(&SIGNIFICATOR_POSITION);
+                let name = ct.get(card).name.to_string();
+                names.push(format!("{position}: {name}"));
+            }
+            for (index, card) in cards.iter().enumerate() {
+                if labelled {
+                    let (position, _) = t.position_text(card.position);
+                    names.push(format!("{position}: {}", full_name(card)));
+                } else {
+                    names.push(full_name(card));
+                }
+
+                for clarifier in reading.clarifiers_for(index) {
+                    names.push(format!(
+                        "  {}: {}",
+                        &t.reading.clarifier,
+                        full_name(clarifier)
+                    ));
+                }
+            }
 
             let payload = names.join("\n");
             match copy_to_clipboard(&payload) {
-                Ok(_) => {
-                    let msg = format_copied_message(&t, names.len());
-                    feedback.set(Feedback::status(msg));
-                }
+                Ok(_) => feedback.set(Feedback::status(Message::Copied {
+                    count: reading.card_count(),
+                })),
                 Err(err) => feedback.set(Feedback::error(err)),
             }
         })
     };
 
+    let handle_send = {
+        let reading = reading.clone();
+        let feedback = feedback.clone();
+        let language = language.clone();
+        Callback::from(move |_| {
+            let Some(payload) = reading.payload(language.code()) else {
+                feedback.set(Feedback::error(Message::DrawFirst));
+                return;
+            };
+            // On success Telegram closes the app, so there is nothing to report.
+            if let Err(err) = send_reading(&payload) {
+                feedback.set(Feedback::error(err));
+            }
+        })
+    };
+
+    let handle_history = {
+        let storage = storage.clone();
+        let past_readings = past_readings.clone();
+        let feedback = feedback.clone();
+        Callback::from(move |_| {
+            let Some(storage) = *storage else {
+                feedback.set(Feedback::error(StorageError::Unavailable));
+                return;
+            };
+            let past_readings = past_readings.clone();
+            let feedback = feedback.clone();
+            spawn_local(async move {
+                match history::load(&storage).await {
+                    Ok(entries) => past_readings.set(Some(entries)),
+                    Err(err) => feedback.set(Feedback::error(err)),
+                }
+            });
+        })
+    };
+
+    let handle_history_close = {
+        let past_readings = past_readings.clone();
+        Callback::from(move |_| past_readings.set(None))
+    };
+
+    let handle_reopen = {
+        let reading = reading.clone();
+        let feedback = feedback.clone();
+        let past_readings = past_readings.clone();
+        let saved_as = saved_as.clone();
+        Callback::from(move |entry: HistoryEntry| {
+            *saved_as.borrow_mut() = Some((entry.seed, entry.clarifiers.len(), entry.saved_at));
+            reading.set(Reading::restore(entry));
+            feedback.set(Feedback::default());
+            past_readings.set(None);
+        })
+    };
+
+    // The BackButton leaves the history screen first, then clears the reading.
+    let handle_back = {
+        let past_readings = past_readings.clone();
+        let handle_reset = handle_reset.clone();
+        Callback::from(move |_| {
+            if past_readings.is_some() {
+                past_readings.set(None);
+            } else {
+                handle_reset.emit(());
+            }
+        })
+    };
+
     let has_cards = reading.has_cards();
     let is_telegram = telegram.available;
+    let can_send = is_telegram && can_send_data();
+    let main_button_sends = has_cards && can_send;
+
+    // Inside Telegram the MainButton carries the primary action: it draws,
+    // then sends the reading back if this launch can, or draws again. Sending
+    // closes the app, so it waits until the reading is in the history. While
+    // it sends, `DrawControls` keeps its own Draw again button.
+    let (main_text, main_action) = match (has_cards, can_send) {
+        (false, _) => (&translations.buttons.draw, handle_draw.clone()),
+        (true, true) => (&translations.buttons.send, handle_send.clone()),
+        (true, false) => (&translations.buttons.draw_again, handle_draw.clone()),
+    };
+    use_main_button(
+        MainButtonState {
+            visible: is_telegram && past_readings.is_none(),
+            text: main_text.to_string(),
+            color: telegram.theme.button_color.clone(),
+            text_color: telegram.theme.button_text_color.clone(),
+            progress: *saving,
+        },
+        main_action,
+    );
 
     use_back_button(
         BackButtonState {
-            visible: is_telegram && has_cards,
+            visible: is_telegram && (has_cards || past_readings.is_some()),
         },
-        handle_reset.clone(),
+        handle_back,
     );
 
     html! {
-        <main class="layout" style={theme_style(&telegram.theme)}>
+        <main
+            class="layout"
+            dir={language.direction().as_str()}
+            lang={language.code()}
+            style={theme_style(&telegram.theme)}
+        >
             <StatusBanner
-                status={feedback.status_text().map(str::to_owned)}
-                error={feedback.error_text().map(str::to_owned)}
-            />
-            <CardGrid
-                cards={reading.cards().to_vec()}
-                translations={translations.clone()}
-                card_translations={card_translations.clone()}
-            />
-            <DrawControls
-                selected={*draw_count}
-                on_select={handle_select}
-                on_draw={handle_draw.clone()}
-                on_copy={handle_copy}
-                on_language_toggle={handle_language_toggle}
-                on_toggle_collapse={handle_controls_toggle}
-                can_copy={reading.has_cards()}
-                collapsed={*controls_collapsed}
-                translations={translations.clone()}
+                status={feedback.status_text(translations)}
+                error={feedback.error_text(translations)}
             />
+            if let Some(entries) = &*past_readings {
+                <HistoryPanel
+                    entries={entries.clone()}
+                    on_open={handle_reopen}
+                    on_close={handle_history_close}
+                    translations={translations}
+                    card_translations={card_translations}
+                />
+            } else {
+                <CardGrid
+                    reading={(*reading).clone()}
+                    reversals={spread.reversal_policy(*reversals)}
+                    on_clarify={handle_clarify}
+                    translations={translations}
+                    card_translations={card_translations}
+                />
+                <VerdictPanel
+                    reading={(*reading).clone()}
+                    translations={translations}
+                    card_translations={card_translations}
+                />
+                <InsightsPanel
+                    reading={(*reading).clone()}
+                    translations={translations}
+                    card_translations={card_translations}
+                />
+                <CombinationsPanel
+                    reading={(*reading).clone()}
+                    language={*language}
+                    translations={translations}
+                    card_translations={card_translations}
+                />
+                <DrawControls
+                    selected={*spread}
+                    on_select={handle_select}
+                    reversals={*reversals}
+                    on_reversals_select={handle_reversals_select}
+                    filter={(*deck_filter).clone()}
+                    on_filter_change={handle_filter_change}
+                    on_draw={handle_draw.clone()}
+                    on_daily={handle_daily}
+                    on_copy={handle_copy}
+                    on_send={handle_send}
+                    on_history={handle_history}
+                    language={*language}
+                    on_language_change={handle_language_change}
+                    on_toggle_collapse={handle_controls_toggle}
+                    can_copy={reading.has_cards()}
+                    can_send={can_send}
+                    has_history={storage.is_some()}
+                    native_draw={is_telegram && !main_button_sends}
+                    drawn={has_cards}
+                    collapsed={*controls_collapsed}
+                    translations={translations}
+                >
+                    <SignificatorPicker
+                        selected={*significator}
+                        on_change={handle_significator_change}
+                        translations={translations}
+                        card_translations={card_translations}
+                    />
+                    <YesNoControls
+                        question={(*question).clone()}
+                        on_question_change={handle_question_change}
+                        on_ask={handle_yes_no}
+                        translations={translations}
+                    />
+                </DrawControls>
+            }
         </main>
     }
 }
+
+/// Deals `spread` from `deck` with `seed` and stores the result, or reports why it failed.
+/// `finish` attaches whatever the caller knows about the reading (significator, question).
+/// `reversals` is the reader's setting; a spread that pins its own policy wins.
+fn deal_reading(
+    deck: Deck,
+    spread: &'static Spread,
+    reversals: ReversalPolicy,
+    seed: u64,
+    finish: impl FnOnce(Reading) -> Reading,
+    reading: &UseStateHandle<Reading>,
+    feedback: &UseStateHandle<Feedback>,
+) {
+    let reversals = spread.reversal_policy(reversals);
+    match deck.draw_seeded(spread, reversals, seed) {
+        Ok(deal) => {
+            reading.set(finish(Reading::new(spread, seed, reversals, deal)));
+            feedback.set(Feedback::default());
+            // Controls stay visible - user can collapse manually
+        }
+        Err(err) => {
+            reading.set(Reading::empty());
+            feedback.set(Feedback::error(err));
+        }
+    }
+}
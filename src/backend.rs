@@ -0,0 +1,99 @@
+//! Calls to the companion bot's HTTP backend (`bot/src/backend.rs`).
+//!
+//! Every call carries the raw, signed `WebApp.initData` as
+//! `Authorization: tma <initData>`, so the backend knows for certain who is
+//! asking. Its address is `BACKEND_URL` at build time; a build without one,
+//! or a page outside Telegram, has no backend to call.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::daily::LocalDate;
+use crate::telegram::init_data;
+
+/// Where the backend is served, e.g. `https://tarot.example:8081`.
+const BACKEND_URL: Option<&str> = option_env!("BACKEND_URL");
+
+/// Why the backend could not answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendError {
+    /// There is no backend to call, or no `initData` to call it with.
+    Unavailable,
+    /// The call failed, or the backend refused it.
+    Failed,
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::Unavailable => write!(f, "The backend is not available here."),
+            BackendError::Failed => write!(f, "The backend call failed."),
+        }
+    }
+}
+
+impl Error for BackendError {}
+
+/// Whether this build knows where the backend is.
+pub fn is_configured() -> bool {
+    BACKEND_URL.is_some_and(|url| !url.is_empty())
+}
+
+/// The seed of the verified user's card of the day on `date`, their local
+/// date, as the backend deals it.
+pub async fn daily_seed(date: LocalDate) -> Result<u64, BackendError> {
+    let url = format!("/api/daily?date={date}");
+    let seed = get_field(&url, "seed").await?;
+    seed.parse().map_err(|_| BackendError::Failed)
+}
+
+/// Calls `GET <BACKEND_URL><path>` and reads the string `field` of the JSON
+/// answer.
+async fn get_field(path: &str, field: &str) -> Result<String, BackendError> {
+    let base = BACKEND_URL
+        .filter(|url| !url.is_empty())
+        .ok_or(BackendError::Unavailable)?;
+    let init_data = init_data().ok_or(BackendError::Unavailable)?;
+    let url = format!("{}{path}", base.trim_end_matches('/'));
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        use js_sys::Reflect;
+        use wasm_bindgen::{JsCast, JsValue};
+        use wasm_bindgen_futures::JsFuture;
+        use web_sys::{Request, RequestInit, Response};
+
+        let init = RequestInit::new();
+        init.set_method("GET");
+        let request =
+            Request::new_with_str_and_init(&url, &init).map_err(|_| BackendError::Failed)?;
+        request
+            .headers()
+            .set("Authorization", &format!("tma {init_data}"))
+            .map_err(|_| BackendError::Failed)?;
+
+        let window = web_sys::window().ok_or(BackendError::Unavailable)?;
+        let response: Response = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .ok()
+            .and_then(|response| response.dyn_into().ok())
+            .ok_or(BackendError::Failed)?;
+        if !response.ok() {
+            return Err(BackendError::Failed);
+        }
+        let body = response.json().map_err(|_| BackendError::Failed)?;
+        let body = JsFuture::from(body)
+            .await
+            .map_err(|_| BackendError::Failed)?;
+        Reflect::get(&body, &JsValue::from_str(field))
+            .ok()
+            .and_then(|value| value.as_string())
+            .ok_or(BackendError::Failed)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (url, init_data, field);
+        Err(BackendError::Unavailable)
+    }
+}
@@ -0,0 +1,142 @@
+//! Well-known meanings of card pairs, such as The Tower with Death.
+//!
+//! The pairs live in `combinations.json`; `build.rs` checks every slug against
+//! the deck and generates [`COMBINATIONS`] from it. Their texts are generated
+//! with the other translations, see [`CombinationTranslations`].
+//!
+//! [`CombinationTranslations`]: crate::i18n::CombinationTranslations
+
+use crate::deck::{DrawnCard, Orientation};
+use crate::i18n::Language;
+use crate::reading::Reading;
+
+/// One side of a combination: a card, optionally only in one orientation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardMatch {
+    pub slug: &'static str,
+    /// `None` matches the card either way up.
+    pub orientation: Option<Orientation>,
+}
+
+impl CardMatch {
+    fn matches(&self, card: &DrawnCard) -> bool {
+        self.slug == card.card.slug
+            && self
+                .orientation
+                .is_none_or(|orientation| orientation == card.orientation)
+    }
+}
+
+/// An unordered pair of cards with a combined meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Combination {
+    pub cards: [CardMatch; 2],
+    /// Position in [`COMBINATIONS`], which also orders the translated texts.
+    pub index: usize,
+}
+
+include!(concat!(env!("OUT_DIR"), "/generated_combinations.rs"));
+
+impl Combination {
+    /// Whether `first` and `second` form this pair, in either order.
+    pub fn matches(&self, first: &DrawnCard, second: &DrawnCard) -> bool {
+        let [a, b] = &self.cards;
+        (a.matches(first) && b.matches(second)) || (a.matches(second) && b.matches(first))
+    }
+
+    /// The combined meaning in `language`, or in the first language of its
+    /// fallback chain that has one.
+    pub fn text(&self, language: Language) -> &'static str {
+        language.combination_translations().get(self)
+    }
+}
+
+/// A combination found among the cards of a reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoundCombination {
+    pub combination: &'static Combination,
+    /// Indices of the two cards in [`Reading::cards`], in spread order.
+    pub cards: (usize, usize),
+}
+
+impl FoundCombination {
+    /// Every known pair among the spread cards of `reading`, in spread order.
+    pub fn in_reading(reading: &Reading) -> Vec<FoundCombination> {
+        Self::in_cards(reading.cards())
+    }
+
+    fn in_cards(cards: &[DrawnCard]) -> Vec<FoundCombination> {
+        let mut found = Vec::new();
+        for (i, first) in cards.iter().enumerate() {
+            for (j, second) in cards.iter().enumerate().skip(i + 1) {
+                found.extend(
+                    COMBINATIONS
+                        .iter()
+                        .filter(|combination| combination.matches(first, second))
+                        .map(|combination| FoundCombination {
+                            combination,
+                            cards: (i, j),
+                        }),
+                );
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::drawn;
+
+    #[test]
+    fn pairs_match_in_either_order() {
+        let cards = drawn(&[
+            ("death", Orientation::Reversed),
+            ("the-fool", Orientation::Upright),
+            ("the-tower", Orientation::Upright),
+        ]);
+        let found = FoundCombination::in_cards(&cards);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].cards, (0, 2));
+        assert!(!found[0]
+            .combination
+            .text(Language::from_code("ua"))
+            .is_empty());
+    }
+
+    #[test]
+    fn orientation_restricts_the_match() {
+        let upright = drawn(&[
+            ("the-star", Orientation::Upright),
+            ("the-moon", Orientation::Upright),
+        ]);
+        let reversed = drawn(&[
+            ("the-star", Orientation::Reversed),
+            ("the-moon", Orientation::Upright),
+        ]);
+
+        assert!(FoundCombination::in_cards(&upright).is_empty());
+        assert_eq!(FoundCombination::in_cards(&reversed).len(), 1);
+    }
+
+    #[test]
+    fn every_language_has_text_for_every_pair() {
+        for language in Language::all() {
+            for combination in COMBINATIONS {
+                assert!(
+                    !combination.text(language).is_empty(),
+                    "{:?} in {}",
+                    combination.cards,
+                    language.code()
+                );
+            }
+        }
+        let ukrainian = Language::from_code("ua").coverage();
+        assert!(!ukrainian
+            .fallbacks
+            .iter()
+            .any(|(key, _)| key.starts_with("combinations.")));
+    }
+}
@@ -0,0 +1,211 @@
+//! "Card of the Day": a draw that stays the same for one user for one local
+//! calendar day.
+//!
+//! The seed only depends on the user id and the date, and is mixed with a
+//! fixed function (SplitMix64) rather than `std`'s hasher, whose output may
+//! change between Rust releases. Anything that knows the user id and date,
+//! such as the bot, can therefore rebuild the same card.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A calendar date in the user's local time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalDate {
+    pub year: i32,
+    /// 1–12.
+    pub month: u32,
+    /// 1–31.
+    pub day: u32,
+}
+
+impl LocalDate {
+    /// Today's date in the browser's time zone, so the daily card turns over
+    /// at local midnight rather than at midnight UTC.
+    #[cfg(target_arch = "wasm32")]
+    pub fn today() -> Self {
+        let now = js_sys::Date::new_0();
+        Self {
+            year: now.get_full_year() as i32,
+            month: now.get_month() + 1,
+            day: now.get_date(),
+        }
+    }
+
+    /// Today's date in UTC; outside the browser there is no local zone to ask.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn today() -> Self {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        Self::from_days_since_epoch((seconds / 86_400) as i64)
+    }
+
+    /// Days since 1970-01-01 in the proleptic Gregorian calendar.
+    pub fn days_since_epoch(self) -> i64 {
+        // Howard Hinnant's `days_from_civil`.
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [`LocalDate::days_since_epoch`].
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // Howard Hinnant's `civil_from_days`.
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+}
+
+/// `YYYY-MM-DD`, as the backend's `date` parameter takes it.
+impl fmt::Display for LocalDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Reads what [`LocalDate`]'s `Display` writes; anything else, including a
+/// day the month does not have, is `Err(())`.
+impl FromStr for LocalDate {
+    type Err = ();
+
+    fn from_str(text: &str) -> Result<Self, ()> {
+        let mut parts = text.splitn(3, '-');
+        let mut next = || parts.next().ok_or(());
+        let date = Self {
+            year: next()?.parse().map_err(|_| ())?,
+            month: next()?.parse().map_err(|_| ())?,
+            day: next()?.parse().map_err(|_| ())?,
+        };
+        // A date that does not exist does not survive the round trip.
+        if Self::from_days_since_epoch(date.days_since_epoch()) != date {
+            return Err(());
+        }
+        Ok(date)
+    }
+}
+
+/// Seed for the daily draw of `user_id` on `date`.
+///
+/// `user_id` is the Telegram user id; outside Telegram the app passes `0`,
+/// which still gives a stable card per day.
+pub fn daily_seed(user_id: i64, date: LocalDate) -> u64 {
+    splitmix64(user_id as u64 ^ splitmix64(date.days_since_epoch() as u64))
+}
+
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::{ReversalPolicy, DAILY_SPREAD};
+
+    #[test]
+    fn converts_dates_to_and_from_epoch_days() {
+        let cases = [
+            (
+                LocalDate {
+                    year: 1970,
+                    month: 1,
+                    day: 1,
+                },
+                0,
+            ),
+            (
+                LocalDate {
+                    year: 2000,
+                    month: 3,
+                    day: 1,
+                },
+                11_017,
+            ),
+            (
+                LocalDate {
+                    year: 2024,
+                    month: 2,
+                    day: 29,
+                },
+                19_782,
+            ),
+            (
+                LocalDate {
+                    year: 1969,
+                    month: 12,
+                    day: 31,
+                },
+                -1,
+            ),
+        ];
+        for (date, days) in cases {
+            assert_eq!(date.days_since_epoch(), days, "{date:?}");
+            assert_eq!(LocalDate::from_days_since_epoch(days), date);
+        }
+    }
+
+    #[test]
+    fn seed_is_stable_per_user_and_day() {
+        let date = LocalDate {
+            year: 2026,
+            month: 10,
+            day: 17,
+        };
+        let next_day = LocalDate {
+            year: 2026,
+            month: 10,
+            day: 18,
+        };
+
+        assert_eq!(daily_seed(42, date), daily_seed(42, date));
+        assert_ne!(daily_seed(42, date), daily_seed(42, next_day));
+        assert_ne!(daily_seed(42, date), daily_seed(43, date));
+    }
+
+    #[test]
+    fn dates_read_back_what_they_write() {
+        let date = LocalDate {
+            year: 2026,
+            month: 10,
+            day: 7,
+        };
+        assert_eq!(date.to_string(), "2026-10-07");
+        assert_eq!("2026-10-07".parse(), Ok(date));
+        for text in ["2026-02-30", "2026-13-01", "2026-10", "today"] {
+            assert_eq!(text.parse::<LocalDate>(), Err(()), "{text}");
+        }
+    }
+
+    #[test]
+    fn daily_card_ignores_the_reversal_setting() {
+        for setting in ReversalPolicy::PRESETS {
+            assert_eq!(
+                DAILY_SPREAD.reversal_policy(setting),
+                ReversalPolicy::default(),
+                "{setting:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::{Arcana, Suit, TarotCard};
+
+/// Which half of the deck to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArcanaFilter {
+    #[default]
+    All,
+    Major,
+    Minor,
+}
+
+/// Narrows the deck before drawing, e.g. "a Major Arcana card only".
+///
+/// `suits` and `courts_only` only restrict Minor Arcana cards; combine them
+/// with [`ArcanaFilter::Minor`] to leave the Major Arcana out as well. An
+/// empty `suits` list keeps every suit.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeckFilter {
+    pub arcana: ArcanaFilter,
+    pub suits: Vec<Suit>,
+    pub courts_only: bool,
+    pub exclude: Vec<&'static str>,
+}
+
+impl DeckFilter {
+    pub fn is_unfiltered(&self) -> bool {
+        *self == DeckFilter::default()
+    }
+
+    pub fn matches(&self, card: &TarotCard) -> bool {
+        if self.exclude.contains(&card.slug) {
+            return false;
+        }
+
+        match card.arcana {
+            Arcana::Major { .. } => self.arcana != ArcanaFilter::Minor,
+            Arcana::Minor { suit, rank } => {
+                self.arcana != ArcanaFilter::Major
+                    && (self.suits.is_empty() || self.suits.contains(&suit))
+                    && (!self.courts_only || rank.is_court())
+            }
+        }
+    }
+}
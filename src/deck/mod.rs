@@ -2,31 +2,59 @@
 //!
 //! The module is intentionally small and documented so newcomers can follow the
 //! data flow:
-//! - [`TarotCard`] holds the static card metadata.
-//! - [`Deck`] gives us a tiny API to draw random cards without touching the data.
-//! - [`DrawCount`] is the user-facing option for how many cards to draw.
-//! - [`DrawnCard`] combines a card with its upright/reversed orientation.
+//! - [`TarotCard`] holds the static card metadata, including its typed
+//!   [`Arcana`] (trump number, or [`Suit`] and [`Rank`]) and deck index.
+//! - [`Deck`] gives us a tiny API to draw random cards without touching the data,
+//!   optionally narrowed by a [`DeckFilter`].
+//!   Draws are driven by a `u64` seed so any reading can be rebuilt later.
+//! - [`Spread`] is the user-facing layout: an ordered list of named positions.
+//! - [`ReversalPolicy`] decides how often cards may land reversed. A spread
+//!   may pin its own, overriding the reader's setting.
+//! - [`DrawnCard`] combines a card with its upright/reversed orientation and
+//!   the spread position it landed in.
+//! - [`Deal`] is the result of a draw: the dealt cards plus the
+//!   [`RemainingDeck`] they were taken from, for clarifiers.
 //!
 //! Everything in here is `Copy`/`Clone`, which keeps the Yew components simple
 //! because we can pass data around without borrowing gymnastics.
 
 mod cards;
+mod filter;
+mod spreads;
+#[cfg(test)]
+mod tests;
 
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use std::error::Error;
 use std::fmt;
 
 pub use cards::CARDS;
+pub use filter::{ArcanaFilter, DeckFilter};
+pub use spreads::{DAILY_SPREAD, SIGNIFICATOR_POSITION, SPREADS, YES_NO_SPREADS};
 
 /// Basic facts for a tarot card.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TarotCard {
     pub slug: &'static str,
     pub name: &'static str,
+    /// Position in the traditional deck order: the Major Arcana 0–21, then
+    /// Wands, Cups, Swords and Pentacles from Ace to King.
+    pub index: u8,
+    pub arcana: Arcana,
+    /// Ruling element: the suit's for the Minor Arcana, the Golden Dawn
+    /// attribution for the Major Arcana.
+    pub element: Element,
+    /// Golden Dawn astrological correspondence in English: a planet or sign
+    /// for the Major Arcana, a decan such as "Mars in Aries" for the pips.
+    /// Aces and court cards have none.
+    pub astrology: Option<&'static str>,
     pub upright: &'static str,
     pub reversed: &'static str,
+    /// What the card answers to a closed question, per orientation.
+    pub yes_no: YesNo,
     pub keywords: &'static [&'static str],
 }
 
@@ -35,37 +63,181 @@ impl TarotCard {
     pub fn image_path(&self) -> String {
         format!("assets/{}.webp", self.slug)
     }
+
+    pub fn is_major(&self) -> bool {
+        matches!(self.arcana, Arcana::Major { .. })
+    }
+
+    /// The court card of `rank` and `suit`, the traditional way to pick a
+    /// significator (rank by age and maturity, suit by temperament).
+    pub fn court(rank: Rank, suit: Suit) -> Option<&'static TarotCard> {
+        if !rank.is_court() {
+            return None;
+        }
+        CARDS
+            .iter()
+            .find(|card| card.arcana == Arcana::Minor { suit, rank })
+    }
+
+    /// The number printed on the card: the trump number for the Major Arcana
+    /// and the rank for the Minor Arcana (Page 11 … King 14).
+    pub fn number(&self) -> u8 {
+        match self.arcana {
+            Arcana::Major { number } => number,
+            Arcana::Minor { rank, .. } => rank as u8,
+        }
+    }
+
+    /// The suit of a Minor Arcana card; `None` for the Major Arcana.
+    pub fn suit(&self) -> Option<Suit> {
+        match self.arcana {
+            Arcana::Major { .. } => None,
+            Arcana::Minor { suit, .. } => Some(suit),
+        }
+    }
 }
 
-/// How many cards we want to draw.
+/// Which half of the deck a card belongs to, with the facts that only make
+/// sense for that half.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum DrawCount {
-    One = 1,
-    Three = 3,
-    Five = 5,
+pub enum Arcana {
+    /// Trump number, from 0 (The Fool) to 21 (The World).
+    Major {
+        number: u8,
+    },
+    Minor {
+        suit: Suit,
+        rank: Rank,
+    },
 }
 
-impl DrawCount {
-    pub const ALL: [DrawCount; 3] = [DrawCount::One, DrawCount::Three, DrawCount::Five];
+/// Minor Arcana suits in traditional deck order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Suit {
+    Wands,
+    Cups,
+    Swords,
+    Pentacles,
+}
 
-    pub fn as_usize(self) -> usize {
-        self as usize
-    }
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Wands, Suit::Cups, Suit::Swords, Suit::Pentacles];
 
-    pub fn label(self) -> &'static str {
+    /// The classical element ruling the suit.
+    pub fn element(self) -> Element {
         match self {
-            DrawCount::One => "Single card",
-            DrawCount::Three => "Three cards",
-            DrawCount::Five => "Five cards",
+            Suit::Wands => Element::Fire,
+            Suit::Cups => Element::Water,
+            Suit::Swords => Element::Air,
+            Suit::Pentacles => Element::Earth,
         }
     }
+}
 
-    pub fn description(self) -> &'static str {
-        match self {
-            DrawCount::One => "A quick pulse check.",
-            DrawCount::Three => "Past · Present · Future.",
-            DrawCount::Five => "Deep-dive spread.",
-        }
+/// The four classical elements, in the order of their suits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Element {
+    Fire,
+    Water,
+    Air,
+    Earth,
+}
+
+/// Minor Arcana ranks. The discriminant is the card's number (Ace = 1,
+/// Page = 11 … King = 14).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Rank {
+    Ace = 1,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Page,
+    Knight,
+    Queen,
+    King,
+}
+
+impl Rank {
+    pub const COURTS: [Rank; 4] = [Rank::Page, Rank::Knight, Rank::Queen, Rank::King];
+
+    /// Page, Knight, Queen and King.
+    pub fn is_court(self) -> bool {
+        self >= Rank::Page
+    }
+}
+
+/// The answer a card gives in the Yes/No oracle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Yes,
+    No,
+    Maybe,
+}
+
+/// A card's [`Answer`] when upright and when reversed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YesNo {
+    pub upright: Answer,
+    pub reversed: Answer,
+}
+
+/// A single slot in a spread, e.g. "Past" in Past · Present · Future.
+///
+/// `title` and `prompt` are the English defaults; the UI looks up localized
+/// versions by `id` and falls back to these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpreadPosition {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub prompt: &'static str,
+}
+
+/// A named layout of positions. Drawing a spread yields one card per position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spread {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub positions: &'static [SpreadPosition],
+    /// The reversal policy this layout always deals with, whatever the reader
+    /// picked in the settings. `None` follows the settings.
+    pub reversals: Option<ReversalPolicy>,
+}
+
+impl Spread {
+    /// The spread selected when the app starts.
+    pub fn default_spread() -> &'static Spread {
+        &SPREADS[0]
+    }
+
+    /// Looks a spread up by its `id` among all layouts, the daily and Yes/No
+    /// ones included.
+    pub fn by_id(id: &str) -> Option<&'static Spread> {
+        SPREADS
+            .iter()
+            .chain(YES_NO_SPREADS)
+            .chain([&DAILY_SPREAD])
+            .find(|spread| spread.id == id)
+    }
+
+    /// The policy a draw of this spread uses when the reader picked `chosen`.
+    pub fn reversal_policy(&self, chosen: ReversalPolicy) -> ReversalPolicy {
+        self.reversals.unwrap_or(chosen)
+    }
+
+    pub fn card_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether this is one of the Yes/No oracle layouts.
+    pub fn is_yes_no(&self) -> bool {
+        YES_NO_SPREADS.iter().any(|spread| spread.id == self.id)
     }
 }
 
@@ -78,34 +250,64 @@ pub enum Orientation {
 }
 
 impl Orientation {
-    pub fn label(self) -> &'static str {
+    pub fn answer(self, card: &TarotCard) -> Answer {
         match self {
-            Orientation::Upright => "Upright",
-            Orientation::Reversed => "Reversed",
+            Orientation::Upright => card.yes_no.upright,
+            Orientation::Reversed => card.yes_no.reversed,
         }
     }
 
-    pub fn meaning(self, card: &TarotCard) -> &'static str {
-        match self {
-            Orientation::Upright => card.upright,
-            Orientation::Reversed => card.reversed,
-        }
-    }
+    fn random<R: Rng + ?Sized>(card: &TarotCard, policy: ReversalPolicy, rng: &mut R) -> Self {
+        let percent = match policy {
+            ReversalPolicy::Never => return Orientation::Upright,
+            ReversalPolicy::MajorsOnly { .. } if !card.is_major() => return Orientation::Upright,
+            ReversalPolicy::Allowed { percent } | ReversalPolicy::MajorsOnly { percent } => percent,
+        };
 
-    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
-        if rng.gen_bool(0.5) {
-            Orientation::Upright
-        } else {
+        if rng.gen_ratio(u32::from(percent.min(100)), 100) {
             Orientation::Reversed
+        } else {
+            Orientation::Upright
         }
     }
 }
 
-/// A tarot card coupled with its randomly chosen orientation.
+/// How reversals are dealt. Readers who never read reversals pick
+/// [`ReversalPolicy::Never`]; the percentages are the chance that an eligible
+/// card lands reversed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReversalPolicy {
+    /// Every card lands upright.
+    Never,
+    /// Any card may land reversed.
+    Allowed { percent: u8 },
+    /// Only Major Arcana cards may land reversed.
+    MajorsOnly { percent: u8 },
+}
+
+impl ReversalPolicy {
+    /// The choices offered in the settings, in display order.
+    pub const PRESETS: [ReversalPolicy; 4] = [
+        ReversalPolicy::Allowed { percent: 50 },
+        ReversalPolicy::Allowed { percent: 25 },
+        ReversalPolicy::MajorsOnly { percent: 50 },
+        ReversalPolicy::Never,
+    ];
+}
+
+impl Default for ReversalPolicy {
+    fn default() -> Self {
+        ReversalPolicy::Allowed { percent: 50 }
+    }
+}
+
+/// A tarot card coupled with its randomly chosen orientation and the spread
+/// position it was dealt into.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DrawnCard {
     pub card: &'static TarotCard,
     pub orientation: Orientation,
+    pub position: &'static SpreadPosition,
 }
 
 impl DrawnCard {
@@ -113,52 +315,104 @@ impl DrawnCard {
         self.card.name
     }
 
-    pub fn full_name(&self) -> String {
-        match self.orientation {
-            Orientation::Upright => self.card.name.to_string(),
-            Orientation::Reversed => format!("{} reversed", self.card.name),
-        }
-    }
-
     pub fn image_path(&self) -> String {
         self.card.image_path()
     }
 
-    pub fn orientation_label(&self) -> &'static str {
-        self.orientation.label()
-    }
-
-    pub fn meaning(&self) -> &'static str {
-        self.orientation.meaning(self.card)
-    }
-
-    pub fn keywords(&self) -> &'static [&'static str] {
-        self.card.keywords
+    pub fn answer(&self) -> Answer {
+        self.orientation.answer(self.card)
     }
 }
 
-/// Thin wrapper around a slice of cards so we can add helper methods.
-#[derive(Debug, Clone, Copy)]
+/// The cards available for a draw, borrowed from the static card data.
+#[derive(Debug, Clone)]
 pub struct Deck {
-    cards: &'static [TarotCard],
+    cards: Vec<&'static TarotCard>,
+    filtered: bool,
 }
 
 impl Deck {
     pub fn new(cards: &'static [TarotCard]) -> Self {
-        Self { cards }
+        Self {
+            cards: cards.iter().collect(),
+            filtered: false,
+        }
     }
 
     pub fn standard() -> Self {
-        Self::new(*CARDS)
+        Self::new(CARDS)
     }
 
-    pub fn draw_random(self, count: DrawCount) -> Result<Vec<DrawnCard>, DeckError> {
+    /// Keeps only the cards that match `filter`.
+    pub fn filter(self, filter: &DeckFilter) -> Self {
+        if filter.is_unfiltered() {
+            return self;
+        }
+
+        Self {
+            cards: self
+                .cards
+                .into_iter()
+                .filter(|card| filter.matches(card))
+                .collect(),
+            filtered: true,
+        }
+    }
+
+    /// Picks a fresh seed for [`Deck::draw_seeded`].
+    pub fn random_seed() -> u64 {
+        thread_rng().gen()
+    }
+
+    /// Deals `spread` from a shuffle fully determined by `seed`.
+    ///
+    /// The same seed, spread, policy and deck always produce the same cards and
+    /// orientations, on every platform. ChaCha8 is used instead of `StdRng`
+    /// because its output is guaranteed not to change between `rand` releases.
+    pub fn draw_seeded(
+        self,
+        spread: &'static Spread,
+        policy: ReversalPolicy,
+        seed: u64,
+    ) -> Result<Deal, DeckError> {
+        self.draw_with_rng(spread, policy, &mut ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Shuffles the deck with `seed` without dealing a spread, e.g. to draw
+    /// clarifiers for a reading whose original shuffle was not kept.
+    pub fn shuffle_seeded(self, policy: ReversalPolicy, seed: u64) -> RemainingDeck {
+        let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+        let mut shuffled = self.cards;
+        shuffled.shuffle(rng);
+        RemainingDeck {
+            cards: shuffled
+                .into_iter()
+                .map(|card| (card, Orientation::random(card, policy, rng)))
+                .collect(),
+        }
+    }
+
+    /// Shuffles the deck with `rng` and deals one card into each position of
+    /// `spread`, orienting each card according to `policy`.
+    ///
+    /// Like a physical deck, every card gets its orientation during the
+    /// shuffle, so cards drawn later from the [`RemainingDeck`] are just as
+    /// reproducible as the spread itself.
+    pub fn draw_with_rng<R: Rng + ?Sized>(
+        self,
+        spread: &'static Spread,
+        policy: ReversalPolicy,
+        rng: &mut R,
+    ) -> Result<Deal, DeckError> {
         let available = self.cards.len();
+        if available == 0 && self.filtered {
+            return Err(DeckError::NoMatchingCards);
+        }
         if available == 0 {
             return Err(DeckError::EmptyDeck);
         }
 
-        let requested = count.as_usize();
+        let requested = spread.card_count();
         if requested > available {
             return Err(DeckError::InsufficientCards {
                 requested,
@@ -166,20 +420,62 @@ impl Deck {
             });
         }
 
-        let mut rng = thread_rng();
-        let mut indices: Vec<usize> = (0..self.cards.len()).collect();
-        indices.shuffle(&mut rng);
+        let mut shuffled = self.cards;
+        shuffled.shuffle(rng);
 
-        let cards = indices
+        let mut oriented = shuffled
             .into_iter()
-            .take(count.as_usize())
-            .map(|index| DrawnCard {
-                card: &self.cards[index],
-                orientation: Orientation::random(&mut rng),
+            .map(|card| (card, Orientation::random(card, policy, rng)));
+
+        let cards = oriented
+            .by_ref()
+            .zip(spread.positions)
+            .map(|((card, orientation), position)| DrawnCard {
+                card,
+                orientation,
+                position,
             })
             .collect();
 
-        Ok(cards)
+        Ok(Deal {
+            cards,
+            remaining: RemainingDeck {
+                cards: oriented.collect(),
+            },
+        })
+    }
+}
+
+/// The outcome of a draw: one card per spread position, plus the rest of the
+/// shuffled deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deal {
+    pub cards: Vec<DrawnCard>,
+    pub remaining: RemainingDeck,
+}
+
+/// The undealt cards of a shuffled deck, in order and already oriented.
+///
+/// Keeping it around lets a reading pull extra cards (clarifiers) without
+/// repeating anything that is already on the table.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RemainingDeck {
+    cards: Vec<(&'static TarotCard, Orientation)>,
+}
+
+impl RemainingDeck {
+    /// Takes the top card and places it in `position`.
+    pub fn draw(&mut self, position: &'static SpreadPosition) -> Result<DrawnCard, DeckError> {
+        if self.cards.is_empty() {
+            return Err(DeckError::NoCardsLeft);
+        }
+
+        let (card, orientation) = self.cards.remove(0);
+        Ok(DrawnCard {
+            card,
+            orientation,
+            position,
+        })
     }
 }
 
@@ -187,7 +483,17 @@ impl Deck {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DeckError {
     EmptyDeck,
-    InsufficientCards { requested: usize, available: usize },
+    /// The deck filter left no cards to draw from.
+    NoMatchingCards,
+    /// `available` counts the cards left after filtering.
+    InsufficientCards {
+        requested: usize,
+        available: usize,
+    },
+    /// Every card of the shuffled deck is already on the table.
+    NoCardsLeft,
+    /// The reading has no card at the position asked for.
+    InvalidPosition,
 }
 
 impl fmt::Display for DeckError {
@@ -197,13 +503,19 @@ impl fmt::Display for DeckError {
                 f,
                 "No cards available. Drop your .webp files inside the assets/ folder and rebuild."
             ),
+            DeckError::NoMatchingCards => write!(
+                f,
+                "No cards match the deck filter. Widen the selection and try again."
+            ),
             DeckError::InsufficientCards {
                 requested,
                 available,
             } => write!(
                 f,
-                "Requested {requested} cards but only {available} exist in the deck."
+                "Requested {requested} cards but only {available} are in the deck."
             ),
+            DeckError::NoCardsLeft => write!(f, "Every card is already on the table."),
+            DeckError::InvalidPosition => write!(f, "There is no card at that position."),
         }
     }
 }
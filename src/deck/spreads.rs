@@ -0,0 +1,154 @@
+use super::{ReversalPolicy, Spread, SpreadPosition};
+
+/// Every spread the app offers, in the order shown by `DrawControls`.
+///
+/// Position ids double as translation keys (`positions.<id>` in
+/// `translations/*.json`), so spreads that share a meaning such as `past`
+/// reuse the same localized title and prompt.
+#[rustfmt::skip]
+pub static SPREADS: &[Spread] = &[
+    Spread {
+        id: "single",
+        label: "Single card",
+        description: "A quick pulse check.",
+        positions: &[
+            SpreadPosition { id: "focus", title: "Focus", prompt: "What needs your attention right now?" },
+        ],
+        reversals: None,
+    },
+    Spread {
+        id: "three",
+        label: "Three cards",
+        description: "Past · Present · Future.",
+        positions: &[
+            SpreadPosition { id: "past", title: "Past", prompt: "What brought you here?" },
+            SpreadPosition { id: "present", title: "Present", prompt: "Where do you stand now?" },
+            SpreadPosition { id: "future", title: "Future", prompt: "Where is this heading?" },
+        ],
+        reversals: None,
+    },
+    Spread {
+        id: "five",
+        label: "Five cards",
+        description: "Deep-dive spread.",
+        positions: &[
+            SpreadPosition { id: "situation", title: "Situation", prompt: "What is happening around you?" },
+            SpreadPosition { id: "challenge", title: "Challenge", prompt: "What stands in the way?" },
+            SpreadPosition { id: "root", title: "Root", prompt: "What lies beneath the surface?" },
+            SpreadPosition { id: "advice", title: "Advice", prompt: "How can you best respond?" },
+            SpreadPosition { id: "outcome", title: "Outcome", prompt: "Where does the current path lead?" },
+        ],
+        reversals: None,
+    },
+    Spread {
+        id: "celtic-cross",
+        label: "Celtic Cross",
+        description: "The classic ten-card spread.",
+        positions: &[
+            SpreadPosition { id: "present", title: "Present", prompt: "Where do you stand now?" },
+            SpreadPosition { id: "challenge", title: "Challenge", prompt: "What stands in the way?" },
+            SpreadPosition { id: "foundation", title: "Foundation", prompt: "What is the root of the matter?" },
+            SpreadPosition { id: "recent-past", title: "Recent past", prompt: "What is moving out of your life?" },
+            SpreadPosition { id: "crown", title: "Crown", prompt: "What is the best you can achieve?" },
+            SpreadPosition { id: "near-future", title: "Near future", prompt: "What is about to arrive?" },
+            SpreadPosition { id: "self", title: "Self", prompt: "How do you see yourself in this?" },
+            SpreadPosition { id: "environment", title: "Environment", prompt: "How do others influence the situation?" },
+            SpreadPosition { id: "hopes-fears", title: "Hopes and fears", prompt: "What do you secretly hope or dread?" },
+            SpreadPosition { id: "outcome", title: "Outcome", prompt: "Where does the current path lead?" },
+        ],
+        reversals: None,
+    },
+    Spread {
+        id: "horseshoe",
+        label: "Horseshoe",
+        description: "Seven cards for a turning point.",
+        positions: &[
+            SpreadPosition { id: "past", title: "Past", prompt: "What brought you here?" },
+            SpreadPosition { id: "present", title: "Present", prompt: "Where do you stand now?" },
+            SpreadPosition { id: "hidden", title: "Hidden influences", prompt: "What are you not seeing?" },
+            SpreadPosition { id: "obstacles", title: "Obstacles", prompt: "What blocks the way forward?" },
+            SpreadPosition { id: "external", title: "External influences", prompt: "Who or what around you plays a part?" },
+            SpreadPosition { id: "advice", title: "Advice", prompt: "How can you best respond?" },
+            SpreadPosition { id: "outcome", title: "Outcome", prompt: "Where does the current path lead?" },
+        ],
+        reversals: None,
+    },
+    Spread {
+        id: "relationship",
+        label: "Relationship",
+        description: "You, them and the bond between.",
+        positions: &[
+            SpreadPosition { id: "you", title: "You", prompt: "What do you bring to the relationship?" },
+            SpreadPosition { id: "partner", title: "Partner", prompt: "What does the other person bring?" },
+            SpreadPosition { id: "connection", title: "Connection", prompt: "What ties you together?" },
+            SpreadPosition { id: "challenge", title: "Challenge", prompt: "What stands in the way?" },
+            SpreadPosition { id: "potential", title: "Potential", prompt: "What can this bond become?" },
+        ],
+        reversals: None,
+    },
+    Spread {
+        id: "career",
+        label: "Career",
+        description: "Work, strengths and the next step.",
+        positions: &[
+            SpreadPosition { id: "current-path", title: "Current path", prompt: "Where does your work stand today?" },
+            SpreadPosition { id: "strengths", title: "Strengths", prompt: "What can you rely on?" },
+            SpreadPosition { id: "obstacles", title: "Obstacles", prompt: "What blocks the way forward?" },
+            SpreadPosition { id: "opportunities", title: "Opportunities", prompt: "Which doors are opening?" },
+            SpreadPosition { id: "next-step", title: "Next step", prompt: "What should you do next?" },
+        ],
+        reversals: None,
+    },
+];
+
+/// The one-card "Card of the Day" layout. It is drawn from a seed derived from
+/// the user and the date (see `crate::daily`), so it is not offered in
+/// [`SPREADS`]. Its reversals are pinned too, so the reader's settings cannot
+/// change the card and the app, the bot and the backend all deal the same one.
+#[rustfmt::skip]
+pub static DAILY_SPREAD: Spread = Spread {
+    id: "daily",
+    label: "Card of the Day",
+    description: "The same card all day long.",
+    positions: &[
+        SpreadPosition { id: "today", title: "Today", prompt: "What does today ask of you?" },
+    ],
+    reversals: Some(ReversalPolicy::Allowed { percent: 50 }),
+};
+
+/// Where the significator (the card standing for the querent) sits. It is
+/// chosen rather than drawn, so no spread lists it.
+pub static SIGNIFICATOR_POSITION: SpreadPosition = SpreadPosition {
+    id: "significator",
+    title: "Significator",
+    prompt: "Who is asking the question?",
+};
+
+/// Layouts for the Yes/No oracle: one card, or three cards tallied together.
+/// Every card answers the same question; each has its own position so the
+/// answers can be told apart.
+/// Cards answer differently upright and reversed, so the oracle always deals
+/// reversals at even odds.
+#[rustfmt::skip]
+pub static YES_NO_SPREADS: &[Spread] = &[
+    Spread {
+        id: "yes-no",
+        label: "1 card",
+        description: "A single card answers.",
+        positions: &[
+            SpreadPosition { id: "answer", title: "Answer", prompt: "What do the cards say?" },
+        ],
+        reversals: Some(ReversalPolicy::Allowed { percent: 50 }),
+    },
+    Spread {
+        id: "yes-no-three",
+        label: "3 cards",
+        description: "The majority decides.",
+        positions: &[
+            SpreadPosition { id: "answer-1", title: "First answer", prompt: "What does the first card say?" },
+            SpreadPosition { id: "answer-2", title: "Second answer", prompt: "What does the second card say?" },
+            SpreadPosition { id: "answer-3", title: "Third answer", prompt: "What does the third card say?" },
+        ],
+        reversals: Some(ReversalPolicy::Allowed { percent: 50 }),
+    },
+];
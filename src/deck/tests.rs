@@ -1,11 +1,343 @@
-#[cfg(test)]
-mod tests {
-    use super::*;
+use super::*;
 
-    #[test]
-    fn deck_draws_requested_number_of_cards() {
-        let deck = Deck::standard();
-        let cards = deck.draw_random(DrawCount::One).expect("draw 1");
-        assert_eq!(cards.len(), 1);
+fn spread(id: &str) -> &'static Spread {
+    SPREADS
+        .iter()
+        .find(|spread| spread.id == id)
+        .expect("known spread")
+}
+
+#[test]
+fn deck_draws_requested_number_of_cards() {
+    let deck = Deck::standard();
+    let cards = deck
+        .draw_seeded(Spread::default_spread(), ReversalPolicy::default(), 1)
+        .expect("draw 1")
+        .cards;
+    assert_eq!(cards.len(), 1);
+}
+
+#[test]
+fn every_spread_deals_one_card_per_position() {
+    for spread in SPREADS {
+        let cards = Deck::standard()
+            .draw_seeded(spread, ReversalPolicy::default(), 7)
+            .expect("draw spread")
+            .cards;
+        let positions: Vec<&str> = cards.iter().map(|card| card.position.id).collect();
+        let expected: Vec<&str> = spread
+            .positions
+            .iter()
+            .map(|position| position.id)
+            .collect();
+        assert_eq!(positions, expected, "spread {}", spread.id);
+    }
+}
+
+#[test]
+fn same_seed_reproduces_cards_and_orientations() {
+    let celtic = spread("celtic-cross");
+    let first = Deck::standard()
+        .draw_seeded(celtic, ReversalPolicy::default(), 123)
+        .expect("draw");
+    let second = Deck::standard()
+        .draw_seeded(celtic, ReversalPolicy::default(), 123)
+        .expect("draw");
+    assert_eq!(first, second);
+}
+
+#[test]
+fn different_seeds_shuffle_differently() {
+    let celtic = spread("celtic-cross");
+    let first = Deck::standard()
+        .draw_seeded(celtic, ReversalPolicy::default(), 1)
+        .expect("draw");
+    let second = Deck::standard()
+        .draw_seeded(celtic, ReversalPolicy::default(), 2)
+        .expect("draw");
+    assert_ne!(first, second);
+}
+
+#[test]
+fn seeded_draw_matches_draw_with_equivalent_rng() {
+    let three = spread("three");
+    let seeded = Deck::standard()
+        .draw_seeded(three, ReversalPolicy::default(), 42)
+        .expect("draw");
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let injected = Deck::standard()
+        .draw_with_rng(three, ReversalPolicy::default(), &mut rng)
+        .expect("draw");
+    assert_eq!(seeded, injected);
+}
+
+#[test]
+fn drawn_cards_never_repeat() {
+    let celtic = spread("celtic-cross");
+    for seed in 0..50 {
+        let cards = Deck::standard()
+            .draw_seeded(celtic, ReversalPolicy::default(), seed)
+            .expect("draw")
+            .cards;
+        let mut slugs: Vec<&str> = cards.iter().map(|card| card.card.slug).collect();
+        slugs.sort_unstable();
+        slugs.dedup();
+        assert_eq!(slugs.len(), celtic.card_count(), "seed {seed}");
+    }
+}
+
+#[test]
+fn oversized_spread_reports_insufficient_cards() {
+    let deck = Deck::new(&CARDS[..3]);
+    let err = deck
+        .draw_seeded(spread("five"), ReversalPolicy::default(), 0)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        DeckError::InsufficientCards {
+            requested: 5,
+            available: 3
+        }
+    );
+}
+
+#[test]
+fn positions_within_a_spread_are_distinct() {
+    for spread in SPREADS.iter().chain(YES_NO_SPREADS).chain([&DAILY_SPREAD]) {
+        let mut ids: Vec<&str> = spread
+            .positions
+            .iter()
+            .map(|position| position.id)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), spread.card_count(), "spread {}", spread.id);
+    }
+}
+
+#[test]
+fn cards_follow_traditional_deck_order() {
+    let indices: Vec<u8> = CARDS.iter().map(|card| card.index).collect();
+    let expected: Vec<u8> = (0..78).collect();
+    assert_eq!(indices, expected);
+    assert_eq!(CARDS[0].slug, "the-fool");
+    assert_eq!(CARDS[21].slug, "the-world");
+    assert_eq!(CARDS[77].slug, "king-of-pentacles");
+}
+
+#[test]
+fn arcana_is_parsed_from_metadata_and_slugs() {
+    let find = |slug: &str| CARDS.iter().find(|card| card.slug == slug).expect(slug);
+
+    assert_eq!(find("strength").arcana, Arcana::Major { number: 8 });
+    assert_eq!(find("wheel-of-fortune").suit(), None);
+    assert_eq!(
+        find("queen-of-swords").arcana,
+        Arcana::Minor {
+            suit: Suit::Swords,
+            rank: Rank::Queen
+        }
+    );
+    assert_eq!(find("ten-of-cups").suit(), Some(Suit::Cups));
+}
+
+#[test]
+fn never_policy_keeps_every_card_upright() {
+    let celtic = spread("celtic-cross");
+    for seed in 0..50 {
+        let cards = Deck::standard()
+            .draw_seeded(celtic, ReversalPolicy::Never, seed)
+            .expect("draw")
+            .cards;
+        assert!(cards
+            .iter()
+            .all(|card| card.orientation == Orientation::Upright));
+    }
+}
+
+#[test]
+fn majors_only_policy_never_reverses_minor_arcana() {
+    let policy = ReversalPolicy::MajorsOnly { percent: 100 };
+    let celtic = spread("celtic-cross");
+    for seed in 0..50 {
+        let cards = Deck::standard()
+            .draw_seeded(celtic, policy, seed)
+            .expect("draw")
+            .cards;
+        for card in cards {
+            let expected = if card.card.is_major() {
+                Orientation::Reversed
+            } else {
+                Orientation::Upright
+            };
+            assert_eq!(card.orientation, expected, "{}", card.card.slug);
+        }
     }
 }
+
+#[test]
+fn spreads_that_pin_a_policy_override_the_setting() {
+    for spread in SPREADS {
+        assert_eq!(
+            spread.reversal_policy(ReversalPolicy::Never),
+            ReversalPolicy::Never,
+            "{}",
+            spread.id
+        );
+    }
+    for spread in YES_NO_SPREADS {
+        assert_eq!(
+            spread.reversal_policy(ReversalPolicy::Never),
+            ReversalPolicy::Allowed { percent: 50 },
+            "{}",
+            spread.id
+        );
+    }
+}
+
+#[test]
+fn policy_does_not_change_which_cards_are_dealt() {
+    let celtic = spread("celtic-cross");
+    let slugs = |policy| {
+        Deck::standard()
+            .draw_seeded(celtic, policy, 9)
+            .expect("draw")
+            .cards
+            .iter()
+            .map(|card| card.card.slug)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        slugs(ReversalPolicy::Never),
+        slugs(ReversalPolicy::default())
+    );
+}
+
+#[test]
+fn majors_only_filter_keeps_the_twenty_two_trumps() {
+    let filter = DeckFilter {
+        arcana: ArcanaFilter::Major,
+        ..DeckFilter::default()
+    };
+    let cards = Deck::standard()
+        .filter(&filter)
+        .draw_seeded(Spread::default_spread(), ReversalPolicy::default(), 5)
+        .expect("draw")
+        .cards;
+    assert!(cards[0].card.is_major());
+
+    let majors = CARDS.iter().filter(|card| filter.matches(card)).count();
+    assert_eq!(majors, 22);
+}
+
+#[test]
+fn suit_court_and_exclusion_filters_combine() {
+    let filter = DeckFilter {
+        arcana: ArcanaFilter::Minor,
+        suits: vec![Suit::Cups],
+        courts_only: true,
+        exclude: vec!["king-of-cups"],
+    };
+    let slugs: Vec<&str> = CARDS
+        .iter()
+        .filter(|card| filter.matches(card))
+        .map(|card| card.slug)
+        .collect();
+    assert_eq!(slugs, ["page-of-cups", "knight-of-cups", "queen-of-cups"]);
+}
+
+#[test]
+fn insufficient_cards_reports_the_filtered_size() {
+    let filter = DeckFilter {
+        arcana: ArcanaFilter::Minor,
+        suits: vec![Suit::Wands],
+        courts_only: true,
+        ..DeckFilter::default()
+    };
+    let err = Deck::standard()
+        .filter(&filter)
+        .draw_seeded(spread("five"), ReversalPolicy::default(), 0)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        DeckError::InsufficientCards {
+            requested: 5,
+            available: 4
+        }
+    );
+}
+
+#[test]
+fn filter_that_matches_nothing_is_reported() {
+    let filter = DeckFilter {
+        arcana: ArcanaFilter::Major,
+        exclude: CARDS.iter().map(|card| card.slug).collect(),
+        ..DeckFilter::default()
+    };
+    let err = Deck::standard()
+        .filter(&filter)
+        .draw_seeded(Spread::default_spread(), ReversalPolicy::default(), 0)
+        .unwrap_err();
+    assert_eq!(err, DeckError::NoMatchingCards);
+}
+
+#[test]
+fn remaining_deck_continues_the_shuffle_without_repeats() {
+    let three = spread("three");
+    let mut deal = Deck::standard()
+        .draw_seeded(three, ReversalPolicy::default(), 11)
+        .expect("draw");
+    let mut seen: Vec<&str> = deal.cards.iter().map(|card| card.card.slug).collect();
+
+    while let Ok(clarifier) = deal.remaining.draw(&three.positions[0]) {
+        assert!(
+            !seen.contains(&clarifier.card.slug),
+            "{}",
+            clarifier.card.slug
+        );
+        seen.push(clarifier.card.slug);
+    }
+
+    assert_eq!(seen.len(), CARDS.len());
+    assert_eq!(
+        deal.remaining.draw(&three.positions[0]),
+        Err(DeckError::NoCardsLeft)
+    );
+}
+
+#[test]
+fn remaining_deck_is_reproducible_from_the_seed() {
+    let three = spread("three");
+    let clarifier = |seed| {
+        let mut deal = Deck::standard()
+            .draw_seeded(three, ReversalPolicy::default(), seed)
+            .expect("draw");
+        deal.remaining.draw(&three.positions[1]).expect("clarifier")
+    };
+    assert_eq!(clarifier(5), clarifier(5));
+}
+
+#[test]
+fn shuffled_deck_holds_every_card_the_filter_keeps() {
+    let filter = DeckFilter {
+        exclude: vec!["the-fool", "the-tower"],
+        ..DeckFilter::default()
+    };
+    let mut remaining = Deck::standard()
+        .filter(&filter)
+        .shuffle_seeded(ReversalPolicy::default(), 3);
+    let mut seen = Vec::new();
+    while let Ok(card) = remaining.draw(&spread("single").positions[0]) {
+        seen.push(card.card.slug);
+    }
+
+    assert_eq!(seen.len(), CARDS.len() - 2);
+    assert!(!seen.contains(&"the-fool") && !seen.contains(&"the-tower"));
+}
+
+#[test]
+fn court_lookup_picks_significators_by_rank_and_suit() {
+    let king = TarotCard::court(Rank::King, Suit::Cups).expect("king of cups");
+    assert_eq!(king.slug, "king-of-cups");
+    assert_eq!(TarotCard::court(Rank::Ace, Suit::Cups), None);
+}
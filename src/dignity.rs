@@ -0,0 +1,103 @@
+//! Elemental dignities: neighbouring cards strengthen or weaken each other
+//! depending on how their elements relate, following the Golden Dawn rules.
+//! Cards of the same element strengthen each other, Fire and Water or Air and
+//! Earth are contrary and weaken each other, and every other pair is friendly.
+
+use std::cmp::Ordering;
+
+use crate::deck::{DrawnCard, Element};
+use crate::reading::Reading;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dignity {
+    Well,
+    Neutral,
+    Ill,
+}
+
+impl Dignity {
+    /// The dignity of every card of `reading`, in card order. Each card is
+    /// judged against the cards dealt just before and after it.
+    pub fn of_reading(reading: &Reading) -> Vec<Dignity> {
+        Self::of_cards(reading.cards())
+    }
+
+    fn of_cards(cards: &[DrawnCard]) -> Vec<Dignity> {
+        (0..cards.len())
+            .map(|index| {
+                let element = cards[index].card.element;
+                let neighbours = index
+                    .checked_sub(1)
+                    .into_iter()
+                    .chain(Some(index + 1).filter(|&next| next < cards.len()));
+                let score: i32 = neighbours
+                    .map(|neighbour| affinity(element, cards[neighbour].card.element))
+                    .sum();
+
+                match score.cmp(&0) {
+                    Ordering::Greater => Dignity::Well,
+                    Ordering::Equal => Dignity::Neutral,
+                    Ordering::Less => Dignity::Ill,
+                }
+            })
+            .collect()
+    }
+}
+
+/// How much a neighbour of element `other` strengthens (positive) or weakens
+/// (negative) a card of element `element`. A contrary neighbour outweighs a
+/// friendly one, so a card between the two ends up ill-dignified.
+fn affinity(element: Element, other: Element) -> i32 {
+    match (element, other) {
+        _ if element == other => 2,
+        (Element::Fire, Element::Water)
+        | (Element::Water, Element::Fire)
+        | (Element::Air, Element::Earth)
+        | (Element::Earth, Element::Air) => -2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::Orientation;
+    use crate::fixtures::drawn;
+
+    /// Dignity only reads elements, so every card is upright.
+    fn dignities(slugs: &[&str]) -> Vec<Dignity> {
+        let cards: Vec<_> = slugs
+            .iter()
+            .map(|&slug| (slug, Orientation::Upright))
+            .collect();
+        Dignity::of_cards(&drawn(&cards))
+    }
+
+    #[test]
+    fn lone_card_is_neutral() {
+        assert_eq!(dignities(&["the-sun"]), [Dignity::Neutral]);
+    }
+
+    #[test]
+    fn contrary_neighbours_weaken_and_friendly_ones_strengthen() {
+        // Fire (Wands) · Water (Cups) · Earth (Pentacles)
+        assert_eq!(
+            dignities(&["two-of-wands", "two-of-cups", "two-of-pentacles"]),
+            [Dignity::Ill, Dignity::Ill, Dignity::Well]
+        );
+    }
+
+    #[test]
+    fn major_arcana_use_their_attributed_element() {
+        // The Emperor (Aries, Fire) next to the Three of Wands (Fire).
+        assert_eq!(
+            dignities(&["the-emperor", "three-of-wands"]),
+            [Dignity::Well, Dignity::Well]
+        );
+        // The Moon (Pisces, Water) balanced between Fire and Water.
+        assert_eq!(
+            dignities(&["ace-of-wands", "the-moon", "ace-of-cups"])[1],
+            Dignity::Neutral
+        );
+    }
+}
@@ -1,29 +1,141 @@
+use crate::deck::DeckError;
+use crate::history::StorageError;
+use crate::i18n::Translations;
+use crate::telegram::{ClipboardError, SendError};
+
+/// Something the status banner reports. It is kept as data and only worded
+/// when shown, so it follows the language the user picks.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Cleared,
+    Copied { count: usize },
+    DrawFirst,
+    AskFirst,
+    Deck(DeckError),
+    Clipboard(ClipboardError),
+    Send(SendError),
+    History(StorageError),
+}
+
+impl Message {
+    pub fn text(&self, t: &Translations) -> String {
+        match self {
+            Message::Cleared => t.feedback.cleared.to_string(),
+            Message::Copied { count } => t.format(t.feedback.copied, &[("count", (*count).into())]),
+            Message::DrawFirst => t.feedback.draw_first.to_string(),
+            Message::AskFirst => t.feedback.ask_first.to_string(),
+            Message::Deck(err) => match err {
+                DeckError::EmptyDeck => t.errors.empty_deck.to_string(),
+                DeckError::NoMatchingCards => t.errors.no_matching_cards.to_string(),
+                DeckError::InsufficientCards {
+                    requested,
+                    available,
+                } => t.format(
+                    t.errors.insufficient_cards,
+                    &[
+                        ("requested", (*requested).into()),
+                        ("available", (*available).into()),
+                    ],
+                ),
+                DeckError::NoCardsLeft => t.errors.no_cards_left.to_string(),
+                DeckError::InvalidPosition => t.errors.invalid_position.to_string(),
+            },
+            Message::Clipboard(err) => match err {
+                ClipboardError::Unavailable => t.errors.clipboard_unavailable.to_string(),
+                ClipboardError::Failed => t.errors.clipboard_failed.to_string(),
+            },
+            Message::Send(err) => match err {
+                SendError::Unavailable => t.errors.send_unavailable.to_string(),
+                SendError::TooLarge { bytes } => {
+                    t.format(t.errors.send_too_large, &[("bytes", (*bytes).into())])
+                }
+            },
+            Message::History(err) => match err {
+                StorageError::Unavailable => t.errors.history_unavailable.to_string(),
+                StorageError::Failed => t.errors.history_failed.to_string(),
+            },
+        }
+    }
+}
+
+impl From<DeckError> for Message {
+    fn from(err: DeckError) -> Self {
+        Message::Deck(err)
+    }
+}
+
+impl From<ClipboardError> for Message {
+    fn from(err: ClipboardError) -> Self {
+        Message::Clipboard(err)
+    }
+}
+
+impl From<SendError> for Message {
+    fn from(err: SendError) -> Self {
+        Message::Send(err)
+    }
+}
+
+impl From<StorageError> for Message {
+    fn from(err: StorageError) -> Self {
+        Message::History(err)
+    }
+}
+
 #[derive(Clone, Default, PartialEq)]
 pub struct Feedback {
-    error: Option<String>,
-    status: Option<String>,
+    error: Option<Message>,
+    status: Option<Message>,
 }
 
 impl Feedback {
-    pub fn error(message: impl Into<String>) -> Self {
+    pub fn error(message: impl Into<Message>) -> Self {
         Self {
             error: Some(message.into()),
             status: None,
         }
     }
 
-    pub fn status(message: impl Into<String>) -> Self {
+    pub fn status(message: impl Into<Message>) -> Self {
         Self {
             error: None,
             status: Some(message.into()),
         }
     }
 
-    pub fn error_text(&self) -> Option<&str> {
-        self.error.as_deref()
+    pub fn error_text(&self, t: &Translations) -> Option<String> {
+        self.error.as_ref().map(|message| message.text(t))
     }
 
-    pub fn status_text(&self) -> Option<&str> {
-        self.status.as_deref()
+    pub fn status_text(&self, t: &Translations) -> Option<String> {
+        self.status.as_ref().map(|message| message.text(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+
+    #[test]
+    fn errors_are_worded_in_the_chosen_language() {
+        let message = Message::from(DeckError::InsufficientCards {
+            requested: 3,
+            available: 1,
+        });
+        let english = message.text(Language::default().translations());
+        let ukrainian = message.text(Language::from_code("uk").translations());
+
+        assert_eq!(english, "Requested 3 cards but only 1 is in the deck.");
+        assert!(ukrainian.contains("3 карти"), "{ukrainian}");
+
+        let message = Message::from(DeckError::InsufficientCards {
+            requested: 5,
+            available: 2,
+        });
+        assert_eq!(
+            message.text(Language::default().translations()),
+            "Requested 5 cards but only 2 are in the deck."
+        );
     }
 }
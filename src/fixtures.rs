@@ -0,0 +1,16 @@
+//! Test fixtures shared by the modules that read a hand of cards.
+
+use crate::deck::{DrawnCard, Orientation, Spread, CARDS};
+
+/// The cards with these slugs, in this order and orientation. They all sit in
+/// the first position of the default spread; nothing under test reads it.
+pub fn drawn(cards: &[(&str, Orientation)]) -> Vec<DrawnCard> {
+    cards
+        .iter()
+        .map(|&(slug, orientation)| DrawnCard {
+            card: CARDS.iter().find(|card| card.slug == slug).expect(slug),
+            orientation,
+            position: &Spread::default_spread().positions[0],
+        })
+        .collect()
+}
@@ -0,0 +1,690 @@
+//! Past readings, kept in Telegram CloudStorage so they follow the user to
+//! every device, or in the browser's `localStorage` outside Telegram.
+//!
+//! CloudStorage takes values of at most 4096 characters, and at most 1024
+//! keys per user. An entry is one line of `|`-separated fields:
+//!
+//! ```text
+//! 3|1718000000000|a50|8841302337421|0131|-c|2|three|en|00952e|-|Will the move go well?
+//! ```
+//!
+//! 1. the line format version, [`HISTORY_VERSION`];
+//! 2. when it was saved, in milliseconds since the Unix epoch;
+//! 3. the reversal policy the cards were dealt with: `n` for never, `a<percent>`
+//!    for any card, `m<percent>` for the Major Arcana only;
+//! 4. the seed of the deal;
+//! 5. the clarifiers, four hex digits each: the index of the clarified card,
+//!    then the card as the payload writes it;
+//! 6. the deck filter: `-` for both arcana, `M` for the Major Arcana only,
+//!    `m` for the Minor Arcana only; then the initials of the suits kept
+//!    (`w`, `c`, `s`, `p`), none for every suit; then `k` for court cards only;
+//! 7. the rest of the line is the reading in the [`ReadingPayload`] encoding.
+//!
+//! Version 2 lines, written before the filter was saved, read back with an
+//! unfiltered deck.
+//!
+//! A long question can push the line over the value limit, so it is split
+//! over as many `reading_<saved_at>_<n>` keys as it needs. The `readings` key
+//! lists the entries, newest first, as `<saved_at>:<chunks>` pairs. Only the
+//! newest [`MAX_ENTRIES`] are kept.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+
+use crate::deck::{ArcanaFilter, DeckFilter, DrawnCard, ReversalPolicy, Suit};
+use crate::payload::{self, ReadingPayload};
+use crate::reading::Clarifier;
+
+/// The line format this build writes. Entries in any other format are skipped.
+const HISTORY_VERSION: u8 = 3;
+
+/// The longest value CloudStorage keeps under one key, in UTF-16 code units
+/// as JavaScript counts string length.
+const MAX_VALUE_CHARS: usize = 4096;
+/// Entries kept before the oldest are dropped. Their index stays well under
+/// [`MAX_VALUE_CHARS`], and their chunks well under CloudStorage's 1024 keys.
+const MAX_ENTRIES: usize = 50;
+const INDEX_KEY: &str = "readings";
+
+/// One saved reading, with all it takes to reopen it: see
+/// `Reading::restore`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// Milliseconds since the Unix epoch. No two entries share it.
+    pub saved_at: u64,
+    pub reading: ReadingPayload,
+    /// The seed the cards were dealt with.
+    pub seed: u64,
+    pub reversals: ReversalPolicy,
+    /// Clarifiers drawn before the reading was saved, oldest first.
+    pub clarifiers: Vec<Clarifier>,
+    /// The filter the cards were dealt from. Its `exclude` list is not saved.
+    pub filter: DeckFilter,
+}
+
+impl HistoryEntry {
+    /// When the entry was saved, e.g. `2024-06-10 14:05`, in local time.
+    pub fn saved_at_text(&self) -> String {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(self.saved_at as f64));
+            format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}",
+                date.get_full_year(),
+                date.get_month() + 1,
+                date.get_date(),
+                date.get_hours(),
+                date.get_minutes()
+            )
+        }
+
+        // Outside the browser there is no local zone to ask, so this is UTC.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let minutes = self.saved_at / 60_000;
+            let date = crate::daily::LocalDate::from_days_since_epoch((minutes / 1440) as i64);
+            format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}",
+                date.year,
+                date.month,
+                date.day,
+                minutes % 1440 / 60,
+                minutes % 60
+            )
+        }
+    }
+
+    fn encode(&self) -> String {
+        let reversals = match self.reversals {
+            ReversalPolicy::Never => "n".to_string(),
+            ReversalPolicy::Allowed { percent } => format!("a{percent}"),
+            ReversalPolicy::MajorsOnly { percent } => format!("m{percent}"),
+        };
+        let clarifiers: String = self
+            .clarifiers
+            .iter()
+            .map(|clarifier| {
+                let card = payload::card_code(clarifier.card.card, clarifier.card.orientation);
+                format!("{:02x}{card:02x}", clarifier.parent)
+            })
+            .collect();
+        format!(
+            "{HISTORY_VERSION}|{}|{reversals}|{}|{clarifiers}|{}|{}",
+            self.saved_at,
+            self.seed,
+            encode_filter(&self.filter),
+            self.reading.to_line()
+        )
+    }
+
+    fn decode(line: &str) -> Option<Self> {
+        let (version, line) = line.split_once('|')?;
+        let has_filter = match version.parse().ok()? {
+            HISTORY_VERSION => true,
+            2 => false,
+            _ => return None,
+        };
+        let mut fields = line.splitn(if has_filter { 6 } else { 5 }, '|');
+        let saved_at = fields.next()?.parse().ok()?;
+        let reversals = fields.next()?;
+        let seed = fields.next()?.parse().ok()?;
+        let clarifiers = fields.next()?;
+        let filter = if has_filter {
+            decode_filter(fields.next()?)?
+        } else {
+            DeckFilter::default()
+        };
+        let reading = ReadingPayload::from_line(fields.next()?).ok()?;
+
+        let reversals = match reversals.split_at_checked(1)? {
+            ("n", "") => ReversalPolicy::Never,
+            ("a", percent) => ReversalPolicy::Allowed {
+                percent: percent.parse().ok()?,
+            },
+            ("m", percent) => ReversalPolicy::MajorsOnly {
+                percent: percent.parse().ok()?,
+            },
+            _ => return None,
+        };
+        if !clarifiers.is_ascii() || clarifiers.len() % 4 != 0 {
+            return None;
+        }
+        let clarifiers = (0..clarifiers.len() / 4)
+            .map(|slot| {
+                let hex = &clarifiers[slot * 4..slot * 4 + 4];
+                let parent = usize::from(u8::from_str_radix(&hex[..2], 16).ok()?);
+                let code = u8::from_str_radix(&hex[2..], 16).ok()?;
+                let (card, orientation) = payload::card_from_code(code).ok()?;
+                Some(Clarifier {
+                    parent,
+                    card: DrawnCard {
+                        card,
+                        orientation,
+                        position: reading.cards.get(parent)?.position,
+                    },
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self {
+            saved_at,
+            reading,
+            seed,
+            reversals,
+            clarifiers,
+            filter,
+        })
+    }
+}
+
+const SUIT_INITIALS: [(Suit, char); 4] = [
+    (Suit::Wands, 'w'),
+    (Suit::Cups, 'c'),
+    (Suit::Swords, 's'),
+    (Suit::Pentacles, 'p'),
+];
+
+fn encode_filter(filter: &DeckFilter) -> String {
+    let mut field = String::from(match filter.arcana {
+        ArcanaFilter::All => '-',
+        ArcanaFilter::Major => 'M',
+        ArcanaFilter::Minor => 'm',
+    });
+    field.extend(
+        SUIT_INITIALS
+            .iter()
+            .filter(|(suit, _)| filter.suits.contains(suit))
+            .map(|&(_, initial)| initial),
+    );
+    if filter.courts_only {
+        field.push('k');
+    }
+    field
+}
+
+fn decode_filter(field: &str) -> Option<DeckFilter> {
+    let mut chars = field.chars();
+    let arcana = match chars.next()? {
+        '-' => ArcanaFilter::All,
+        'M' => ArcanaFilter::Major,
+        'm' => ArcanaFilter::Minor,
+        _ => return None,
+    };
+    let mut filter = DeckFilter {
+        arcana,
+        ..DeckFilter::default()
+    };
+    for letter in chars {
+        match SUIT_INITIALS
+            .iter()
+            .find(|&&(_, initial)| initial == letter)
+        {
+            Some(&(suit, _)) => filter.suits.push(suit),
+            None if letter == 'k' => filter.courts_only = true,
+            None => return None,
+        }
+    }
+    Some(filter)
+}
+
+/// Why the history could not be read or written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageError {
+    /// Neither CloudStorage nor `localStorage` is available here.
+    Unavailable,
+    /// The store refused or failed the call.
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    Failed,
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::Unavailable => write!(f, "Reading history is not available here."),
+            StorageError::Failed => write!(f, "Reading history could not be saved or loaded."),
+        }
+    }
+}
+
+impl Error for StorageError {}
+
+/// A string key/value store with CloudStorage's limits, see
+/// `BrowserStorage` in `src/telegram.rs`.
+pub trait Storage {
+    /// The value of each of `keys`, `None` where a key is not set.
+    async fn get_items(&self, keys: &[String]) -> Result<Vec<Option<String>>, StorageError>;
+    async fn set_item(&self, key: &str, value: &str) -> Result<(), StorageError>;
+    async fn remove_items(&self, keys: &[String]) -> Result<(), StorageError>;
+}
+
+/// The saved readings, newest first. Entries that cannot be read back, e.g.
+/// ones a newer build wrote, are skipped.
+pub async fn load(storage: &impl Storage) -> Result<Vec<HistoryEntry>, StorageError> {
+    let slots = read_index(storage).await?;
+    let keys: Vec<String> = slots.iter().flat_map(|slot| slot.keys()).collect();
+    let mut values = storage.get_items(&keys).await?.into_iter();
+
+    Ok(slots
+        .iter()
+        .filter_map(|slot| {
+            let chunks: Vec<Option<String>> = values.by_ref().take(slot.chunks).collect();
+            let line: Option<String> = chunks.into_iter().collect();
+            HistoryEntry::decode(&line?)
+        })
+        .collect())
+}
+
+/// Saves `entry` as the newest reading and drops those past [`MAX_ENTRIES`].
+pub async fn save(storage: &impl Storage, entry: &HistoryEntry) -> Result<(), StorageError> {
+    let chunks = chunk(&entry.encode());
+    let slot = Slot {
+        saved_at: entry.saved_at,
+        chunks: chunks.len(),
+    };
+    // The chunks go first, so the index never lists an entry that is not stored.
+    for (key, value) in slot.keys().zip(&chunks) {
+        storage.set_item(&key, value).await?;
+    }
+
+    let mut slots = read_index(storage).await?;
+    slots.retain(|saved| saved.saved_at != slot.saved_at);
+    slots.insert(0, slot);
+    let dropped = slots.split_off(slots.len().min(MAX_ENTRIES));
+    storage.set_item(INDEX_KEY, &write_index(&slots)).await?;
+
+    let stale: Vec<String> = dropped.iter().flat_map(|slot| slot.keys()).collect();
+    if !stale.is_empty() {
+        storage.remove_items(&stale).await?;
+    }
+    Ok(())
+}
+
+/// Runs [`save`]s one at a time. Each save reads, edits and rewrites the
+/// index, so two that overlap would each drop the other's entry from it and
+/// leave its chunks behind.
+#[derive(Default)]
+pub struct SaveQueue {
+    queued: RefCell<VecDeque<HistoryEntry>>,
+    running: Cell<bool>,
+}
+
+impl SaveQueue {
+    /// Queues `entry`. Unless a save is already running, this call then saves
+    /// every queued entry in order into `storage`, and reports the first
+    /// error; otherwise it returns at once and the running call saves it.
+    pub async fn save(
+        &self,
+        storage: &impl Storage,
+        entry: HistoryEntry,
+    ) -> Result<(), StorageError> {
+        self.queued.borrow_mut().push_back(entry);
+        if self.running.replace(true) {
+            return Ok(());
+        }
+
+        let mut result = Ok(());
+        loop {
+            let next = self.queued.borrow_mut().pop_front();
+            let Some(entry) = next else { break };
+            let saved = save(storage, &entry).await;
+            result = result.and(saved);
+        }
+        self.running.set(false);
+        result
+    }
+
+    /// Whether a save is running.
+    pub fn is_busy(&self) -> bool {
+        self.running.get()
+    }
+}
+
+/// Where an entry is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Slot {
+    saved_at: u64,
+    /// How many keys the entry's line is split over.
+    chunks: usize,
+}
+
+impl Slot {
+    fn keys(self) -> impl Iterator<Item = String> {
+        (0..self.chunks).map(move |chunk| format!("reading_{}_{chunk}", self.saved_at))
+    }
+}
+
+async fn read_index(storage: &impl Storage) -> Result<Vec<Slot>, StorageError> {
+    let index = storage.get_items(&[INDEX_KEY.to_string()]).await?;
+    Ok(index
+        .into_iter()
+        .flatten()
+        .flat_map(|index| parse_index(&index))
+        .collect())
+}
+
+/// Reads what [`write_index`] wrote, skipping pairs that do not parse.
+fn parse_index(index: &str) -> Vec<Slot> {
+    index
+        .split(',')
+        .filter_map(|pair| {
+            let (saved_at, chunks) = pair.split_once(':')?;
+            Some(Slot {
+                saved_at: saved_at.parse().ok()?,
+                chunks: chunks.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+fn write_index(slots: &[Slot]) -> String {
+    slots
+        .iter()
+        .map(|slot| format!("{}:{}", slot.saved_at, slot.chunks))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Splits `value` into pieces of at most [`MAX_VALUE_CHARS`], never inside a
+/// character.
+fn chunk(value: &str) -> Vec<String> {
+    let mut pieces = vec![String::new()];
+    let mut length = 0;
+    for character in value.chars() {
+        if length + character.len_utf16() > MAX_VALUE_CHARS {
+            pieces.push(String::new());
+            length = 0;
+        }
+        length += character.len_utf16();
+        pieces.last_mut().unwrap().push(character);
+    }
+    pieces
+}
+
+/// Milliseconds since the Unix epoch, as [`HistoryEntry::saved_at`] counts.
+pub fn now_millis() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now() as u64
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use super::*;
+    use crate::deck::{Deck, Spread, CARDS};
+    use crate::reading::Reading;
+
+    /// CloudStorage in memory, refusing values over its limit.
+    #[derive(Default)]
+    struct MemoryStorage(RefCell<BTreeMap<String, String>>);
+
+    impl Storage for MemoryStorage {
+        async fn get_items(&self, keys: &[String]) -> Result<Vec<Option<String>>, StorageError> {
+            let items = self.0.borrow();
+            Ok(keys.iter().map(|key| items.get(key).cloned()).collect())
+        }
+
+        async fn set_item(&self, key: &str, value: &str) -> Result<(), StorageError> {
+            if value.encode_utf16().count() > MAX_VALUE_CHARS {
+                return Err(StorageError::Failed);
+            }
+            self.0
+                .borrow_mut()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        async fn remove_items(&self, keys: &[String]) -> Result<(), StorageError> {
+            let mut items = self.0.borrow_mut();
+            for key in keys {
+                items.remove(key);
+            }
+            Ok(())
+        }
+    }
+
+    /// `MemoryStorage` that waits once before every call, as CloudStorage
+    /// does, so that saves can overlap.
+    #[derive(Default)]
+    struct SlowStorage(MemoryStorage);
+
+    impl Storage for SlowStorage {
+        async fn get_items(&self, keys: &[String]) -> Result<Vec<Option<String>>, StorageError> {
+            wait_once().await;
+            self.0.get_items(keys).await
+        }
+
+        async fn set_item(&self, key: &str, value: &str) -> Result<(), StorageError> {
+            wait_once().await;
+            self.0.set_item(key, value).await
+        }
+
+        async fn remove_items(&self, keys: &[String]) -> Result<(), StorageError> {
+            wait_once().await;
+            self.0.remove_items(keys).await
+        }
+    }
+
+    async fn wait_once() {
+        let mut waited = false;
+        std::future::poll_fn(|_| {
+            if std::mem::replace(&mut waited, true) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Runs a future that never waits, as every `MemoryStorage` call is.
+    fn ready<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("MemoryStorage never waits"),
+        }
+    }
+
+    fn entry(saved_at: u64, question: Option<String>) -> HistoryEntry {
+        let spread = Spread::by_id("three").unwrap();
+        let deal = Deck::standard()
+            .draw_seeded(spread, ReversalPolicy::default(), saved_at)
+            .unwrap();
+        HistoryEntry {
+            saved_at,
+            reading: ReadingPayload {
+                spread,
+                cards: deal.cards,
+                significator: None,
+                question,
+                locale: "en".to_string(),
+            },
+            seed: saved_at,
+            reversals: ReversalPolicy::default(),
+            clarifiers: Vec::new(),
+            filter: DeckFilter::default(),
+        }
+    }
+
+    #[test]
+    fn saved_readings_load_newest_first() {
+        let storage = MemoryStorage::default();
+        let first = entry(1_000, None);
+        let second = entry(2_000, Some("Stay | or go?".to_string()));
+
+        ready(save(&storage, &first)).unwrap();
+        ready(save(&storage, &second)).unwrap();
+
+        assert_eq!(ready(load(&storage)), Ok(vec![second, first]));
+    }
+
+    #[test]
+    fn long_questions_are_split_over_several_keys() {
+        let storage = MemoryStorage::default();
+        // Each emoji is two UTF-16 code units, as CloudStorage counts them.
+        let long = entry(1_000, Some("🔮".repeat(MAX_VALUE_CHARS)));
+
+        ready(save(&storage, &long)).unwrap();
+
+        assert_eq!(storage.0.borrow()[INDEX_KEY], "1000:3");
+        assert_eq!(ready(load(&storage)), Ok(vec![long]));
+    }
+
+    #[test]
+    fn only_the_newest_entries_are_kept() {
+        let storage = MemoryStorage::default();
+        for saved_at in 0..=MAX_ENTRIES as u64 {
+            ready(save(&storage, &entry(saved_at, None))).unwrap();
+        }
+
+        let loaded = ready(load(&storage)).unwrap();
+        assert_eq!(loaded.len(), MAX_ENTRIES);
+        assert_eq!(loaded[0].saved_at, MAX_ENTRIES as u64);
+        assert!(!storage.0.borrow().contains_key("reading_0_0"));
+    }
+
+    #[test]
+    fn overlapping_saves_keep_both_entries() {
+        let storage = SlowStorage::default();
+        let saves = SaveQueue::default();
+        let mut first = pin!(saves.save(&storage, entry(1_000, None)));
+        let mut second = pin!(saves.save(&storage, entry(2_000, None)));
+
+        // Take turns, as two saves spawned one after the other do.
+        let context = &mut Context::from_waker(Waker::noop());
+        let (mut first_done, mut second_done) = (None, None);
+        while first_done.is_none() || second_done.is_none() {
+            if first_done.is_none() {
+                first_done = match first.as_mut().poll(context) {
+                    Poll::Ready(result) => Some(result),
+                    Poll::Pending => None,
+                };
+            }
+            if second_done.is_none() {
+                second_done = match second.as_mut().poll(context) {
+                    Poll::Ready(result) => Some(result),
+                    Poll::Pending => None,
+                };
+            }
+        }
+        assert_eq!(first_done, Some(Ok(())));
+        assert_eq!(second_done, Some(Ok(())));
+        assert!(!saves.is_busy());
+
+        let loaded = ready(load(&storage.0)).unwrap();
+        assert_eq!(loaded, vec![entry(2_000, None), entry(1_000, None)]);
+    }
+
+    #[test]
+    fn unreadable_entries_are_skipped() {
+        let storage = MemoryStorage::default();
+        ready(save(&storage, &entry(1_000, None))).unwrap();
+        ready(storage.set_item("reading_2000_0", "2000|9|three|en||")).unwrap();
+        ready(storage.set_item(INDEX_KEY, "2000:1,junk,3000:1,1000:1")).unwrap();
+
+        let loaded = ready(load(&storage)).unwrap();
+        assert_eq!(loaded, vec![entry(1_000, None)]);
+    }
+
+    #[test]
+    fn reopened_readings_keep_their_deal() {
+        let storage = MemoryStorage::default();
+        let spread = Spread::by_id("three").unwrap();
+        let reversals = ReversalPolicy::MajorsOnly { percent: 25 };
+        let significator = CARDS.iter().find(|card| card.slug == "queen-of-cups");
+        let filter = DeckFilter {
+            exclude: vec!["queen-of-cups"],
+            ..DeckFilter::default()
+        };
+        let deal = Deck::standard()
+            .filter(&filter)
+            .draw_seeded(spread, reversals, 42)
+            .unwrap();
+        let mut reading = Reading::new(spread, 42, reversals, deal).with_significator(significator);
+        reading.draw_clarifier(1).unwrap();
+
+        ready(save(&storage, &reading.history_entry("en", 1_000).unwrap())).unwrap();
+        let [saved] = <[HistoryEntry; 1]>::try_from(ready(load(&storage)).unwrap()).unwrap();
+        let mut reopened = Reading::restore(saved);
+
+        assert_eq!(reopened.seed(), Some(42));
+        assert_eq!(reopened.reversals(), reversals);
+        assert_eq!(reopened.significator(), significator);
+        assert_eq!(reopened.cards(), reading.cards());
+        assert_eq!(reopened.clarifiers(), reading.clarifiers());
+
+        // Further clarifiers never repeat a card that is on the table.
+        reopened.draw_clarifier(0).unwrap();
+        let drawn = reopened.clarifiers().last().unwrap().card.card;
+        assert_ne!(Some(drawn), significator);
+        assert!(reading.cards().iter().all(|card| card.card != drawn));
+        assert!(reading
+            .clarifiers()
+            .iter()
+            .all(|clarifier| clarifier.card.card != drawn));
+    }
+
+    #[test]
+    fn reopened_readings_draw_from_their_filter() {
+        let storage = MemoryStorage::default();
+        let spread = Spread::by_id("three").unwrap();
+        let filter = DeckFilter {
+            arcana: ArcanaFilter::Minor,
+            suits: vec![Suit::Cups],
+            courts_only: true,
+            ..DeckFilter::default()
+        };
+        let deal = Deck::standard()
+            .filter(&filter)
+            .draw_seeded(spread, ReversalPolicy::Never, 7)
+            .unwrap();
+        let reading =
+            Reading::new(spread, 7, ReversalPolicy::Never, deal).with_filter(filter.clone());
+
+        let entry = reading.history_entry("en", 1_000).unwrap();
+        assert!(entry.encode().starts_with("3|1000|n|7||mck|"));
+        ready(save(&storage, &entry)).unwrap();
+        let [saved] = <[HistoryEntry; 1]>::try_from(ready(load(&storage)).unwrap()).unwrap();
+        assert_eq!(saved.filter, filter);
+
+        // Three of the four Cup courts are on the table; only the last is left.
+        let mut reopened = Reading::restore(saved);
+        reopened.draw_clarifier(0).unwrap();
+        let drawn = reopened.clarifiers()[0].card.card;
+        assert!(filter.matches(drawn));
+        assert!(reading.cards().iter().all(|card| card.card != drawn));
+        assert!(reopened.draw_clarifier(0).is_err());
+    }
+
+    #[test]
+    fn version_2_entries_read_back_unfiltered() {
+        let saved = entry(1_000, None);
+        let line = format!("2|1000|a50|1000||{}", saved.reading.to_line());
+
+        assert_eq!(HistoryEntry::decode(&line), Some(saved));
+    }
+
+    #[test]
+    fn saved_at_reads_as_a_date_and_time() {
+        assert_eq!(
+            entry(1_718_028_300_000, None).saved_at_text(),
+            "2024-06-10 14:05"
+        );
+    }
+}
@@ -1,132 +1,518 @@
-use serde::Deserialize;
-use std::collections::HashMap;
+//! UI and card text in every supported language.
+//!
+//! `build.rs` compiles `translations/<lang>.json`,
+//! `translations/cards_<lang>.json` and the pair texts of `combinations.json`
+//! into the static tables included below, so a missing or misspelt key is
+//! caught at build time and looking text up costs nothing at runtime.
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+use std::fmt;
+
+use crate::combinations::Combination;
+use crate::deck::{Answer, Element, Rank, ReversalPolicy, Spread, SpreadPosition, Suit, TarotCard};
+use crate::message::{self, Arg, PluralRules};
+
+#[derive(Debug, PartialEq)]
 pub struct Translations {
-    pub language: String,
     pub buttons: ButtonTranslations,
-    pub spreads: SpreadTranslations,
+    /// Spread texts by spread id.
+    pub spreads: &'static [(&'static str, SpreadOption)],
+    /// Position texts by position id.
+    pub positions: &'static [(&'static str, PositionTranslation)],
+    pub reversals: ReversalTranslations,
+    pub deck: DeckTranslations,
+    pub significator: SignificatorTranslations,
     pub empty: EmptyTranslations,
+    pub reading: ReadingTranslations,
+    pub insights: InsightTranslations,
+    pub elements: ElementTranslations,
+    pub yes_no: YesNoTranslations,
+    pub history: HistoryTranslations,
     pub feedback: FeedbackTranslations,
+    pub errors: ErrorTranslations,
     pub orientation: OrientationTranslations,
+    /// How the language counts, from `locales.json`.
+    pub plural: PluralRules,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+/// The texts of one card. `build.rs` resolves each field along the language's
+/// fallback chain, so a card is never left without text.
+#[derive(Debug, PartialEq)]
 pub struct CardTranslation {
-    #[serde(default)]
-    pub name: Option<String>,
-    pub upright: String,
-    pub reversed: String,
-    pub keywords: Vec<String>,
+    pub name: &'static str,
+    /// Localized astrological correspondence, e.g. "Mars in Aries".
+    pub astrology: Option<&'static str>,
+    pub upright: &'static str,
+    pub reversed: &'static str,
+    pub keywords: &'static [&'static str],
 }
 
-pub type CardTranslations = HashMap<String, CardTranslation>;
+/// Card texts of one language, in deck order.
+#[derive(Debug, PartialEq)]
+pub struct CardTranslations(&'static [CardTranslation]);
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-pub struct ButtonTranslations {
-    pub draw: String,
-    pub copy: String,
-    pub language: String,
-    pub hide: String,
+impl CardTranslations {
+    pub fn get(&self, card: &TarotCard) -> &'static CardTranslation {
+        let entries: &'static [CardTranslation] = self.0;
+        &entries[usize::from(card.index)]
+    }
+}
+
+/// The combined meanings of [`COMBINATIONS`] in one language, in the same
+/// order. `build.rs` resolves each along the language's fallback chain.
+///
+/// [`COMBINATIONS`]: crate::combinations::COMBINATIONS
+#[derive(Debug, PartialEq)]
+pub struct CombinationTranslations(&'static [&'static str]);
+
+impl CombinationTranslations {
+    pub fn get(&self, combination: &Combination) -> &'static str {
+        let texts: &'static [&'static str] = self.0;
+        texts[combination.index]
+    }
+}
+
+/// How much of a language is its own rather than taken from a fallback.
+#[derive(Debug, PartialEq)]
+pub struct TranslationCoverage {
+    /// UI and card texts the language ends up with.
+    pub keys: usize,
+    /// `(key, language the text came from)` for every text that fell back,
+    /// e.g. `("cards.the-fool.upright", "en")`.
+    pub fallbacks: &'static [(&'static str, &'static str)],
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-pub struct SpreadTranslations {
-    pub single: SpreadOption,
-    pub three: SpreadOption,
-    pub five: SpreadOption,
+#[derive(Debug, PartialEq)]
+pub struct ButtonTranslations {
+    pub draw: &'static str,
+    pub draw_again: &'static str,
+    pub daily: &'static str,
+    pub copy: &'static str,
+    pub send: &'static str,
+    pub history: &'static str,
+    pub clarify: &'static str,
+    pub language: &'static str,
+    pub hide: &'static str,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq)]
 pub struct SpreadOption {
-    pub label: String,
-    pub description: String,
+    pub label: &'static str,
+    pub description: &'static str,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq)]
+pub struct PositionTranslation {
+    pub title: &'static str,
+    pub prompt: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ReversalTranslations {
+    pub label: &'static str,
+    pub never: &'static str,
+    pub allowed: &'static str,
+    pub majors_only: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DeckTranslations {
+    pub label: &'static str,
+    pub all: &'static str,
+    pub major: &'static str,
+    pub minor: &'static str,
+    pub courts: &'static str,
+    pub suits: SuitTranslations,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SuitTranslations {
+    pub wands: &'static str,
+    pub cups: &'static str,
+    pub swords: &'static str,
+    pub pentacles: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SignificatorTranslations {
+    pub label: &'static str,
+    pub none: &'static str,
+    pub court: &'static str,
+    pub suit: &'static str,
+    pub ranks: CourtRankTranslations,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CourtRankTranslations {
+    pub page: &'static str,
+    pub knight: &'static str,
+    pub queen: &'static str,
+    pub king: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
 pub struct EmptyTranslations {
-    pub title: String,
-    pub subtitle: String,
+    pub title: &'static str,
+    pub title_upright: &'static str,
+    pub title_majors: &'static str,
+    pub subtitle: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ReadingTranslations {
+    pub seed: &'static str,
+    pub clarifier: &'static str,
+    pub well_dignified: &'static str,
+    pub ill_dignified: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InsightTranslations {
+    pub title: &'static str,
+    pub arcana: &'static str,
+    pub dominant: &'static str,
+    pub missing: &'static str,
+    pub reversed: &'static str,
+    pub repeated: &'static str,
+    pub numerology: &'static str,
+    pub combinations: &'static str,
+    pub ranks: PipRankTranslations,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq)]
+pub struct PipRankTranslations {
+    pub ace: &'static str,
+    pub two: &'static str,
+    pub three: &'static str,
+    pub four: &'static str,
+    pub five: &'static str,
+    pub six: &'static str,
+    pub seven: &'static str,
+    pub eight: &'static str,
+    pub nine: &'static str,
+    pub ten: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ElementTranslations {
+    pub fire: &'static str,
+    pub water: &'static str,
+    pub air: &'static str,
+    pub earth: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct YesNoTranslations {
+    pub label: &'static str,
+    pub placeholder: &'static str,
+    pub question: &'static str,
+    pub verdict: &'static str,
+    pub yes: &'static str,
+    pub no: &'static str,
+    pub maybe: &'static str,
+    pub tally: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct HistoryTranslations {
+    pub title: &'static str,
+    pub empty: &'static str,
+    pub close: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
 pub struct FeedbackTranslations {
-    pub cleared: String,
-    pub copied: String,
-    pub draw_first: String,
+    pub cleared: &'static str,
+    pub copied: &'static str,
+    pub draw_first: &'static str,
+    pub ask_first: &'static str,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-pub struct OrientationTranslations {
-    pub upright: String,
-    pub reversed: String,
+/// Wording for `DeckError`, `ClipboardError`, `SendError` and `StorageError`,
+/// see `Message` in `src/feedback.rs`.
+#[derive(Debug, PartialEq)]
+pub struct ErrorTranslations {
+    pub empty_deck: &'static str,
+    pub no_matching_cards: &'static str,
+    pub insufficient_cards: &'static str,
+    pub no_cards_left: &'static str,
+    pub invalid_position: &'static str,
+    pub clipboard_unavailable: &'static str,
+    pub clipboard_failed: &'static str,
+    pub send_unavailable: &'static str,
+    pub send_too_large: &'static str,
+    pub history_unavailable: &'static str,
+    pub history_failed: &'static str,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Language {
-    English,
-    Ukrainian,
+#[derive(Debug, PartialEq)]
+pub struct OrientationTranslations {
+    pub upright: &'static str,
+    pub reversed: &'static str,
 }
 
-impl Language {
-    pub fn code(&self) -> &'static str {
-        match self {
-            Language::English => "en",
-            Language::Ukrainian => "ua",
+include!(concat!(env!("OUT_DIR"), "/generated_translations.rs"));
+
+impl Translations {
+    /// Fills the named `args` into `message`, one of this language's texts.
+    pub fn format(&self, message: &str, args: &[(&str, Arg)]) -> String {
+        message::format(message, self.plural, args)
+    }
+
+    /// Localized `(label, description)` for a spread, falling back to the
+    /// English text defined next to the spread.
+    pub fn spread_text(&self, spread: &'static Spread) -> (&'static str, &'static str) {
+        match self.spreads.iter().find(|(id, _)| *id == spread.id) {
+            Some((_, option)) => (option.label, option.description),
+            None => (spread.label, spread.description),
         }
     }
 
-    pub fn from_code(code: &str) -> Self {
-        match code {
-            "ua" | "uk" => Language::Ukrainian,
-            _ => Language::English,
+    /// Short label for a reversal policy option in the settings.
+    pub fn reversal_label(&self, policy: ReversalPolicy) -> String {
+        match policy {
+            ReversalPolicy::Never => self.reversals.never.to_string(),
+            ReversalPolicy::Allowed { percent } => {
+                self.format(self.reversals.allowed, &[("percent", percent.into())])
+            }
+            ReversalPolicy::MajorsOnly { .. } => self.reversals.majors_only.to_string(),
         }
     }
 
-    pub fn load_translations(&self) -> Translations {
-        let json = match self {
-            Language::English => include_str!("../translations/en.json"),
-            Language::Ukrainian => include_str!("../translations/ua.json"),
-        };
+    /// Empty-state headline describing what the chosen policy allows.
+    pub fn empty_title(&self, policy: ReversalPolicy) -> &'static str {
+        match policy {
+            ReversalPolicy::Never => self.empty.title_upright,
+            ReversalPolicy::Allowed { .. } => self.empty.title,
+            ReversalPolicy::MajorsOnly { .. } => self.empty.title_majors,
+        }
+    }
 
-        serde_json::from_str(json).unwrap_or_else(|_| {
-            // Fallback to English if parsing fails
-            serde_json::from_str(include_str!("../translations/en.json"))
-                .expect("English translations must be valid")
-        })
+    pub fn suit_name(&self, suit: Suit) -> &'static str {
+        match suit {
+            Suit::Wands => self.deck.suits.wands,
+            Suit::Cups => self.deck.suits.cups,
+            Suit::Swords => self.deck.suits.swords,
+            Suit::Pentacles => self.deck.suits.pentacles,
+        }
     }
 
-    pub fn load_card_translations(&self) -> CardTranslations {
-        let json = match self {
-            Language::English => include_str!("../translations/cards_en.json"),
-            Language::Ukrainian => include_str!("../translations/cards_ua.json"),
-        };
+    /// Name of a court rank; pip ranks have none and return an empty string.
+    pub fn court_rank_name(&self, rank: Rank) -> &'static str {
+        match rank {
+            Rank::Page => self.significator.ranks.page,
+            Rank::Knight => self.significator.ranks.knight,
+            Rank::Queen => self.significator.ranks.queen,
+            Rank::King => self.significator.ranks.king,
+            _ => "",
+        }
+    }
 
-        serde_json::from_str(json).unwrap_or_else(|_| {
-            // Fallback to English if parsing fails
-            serde_json::from_str(include_str!("../translations/cards_en.json"))
-                .expect("English card translations must be valid")
-        })
+    /// Name of any Minor Arcana rank, from Ace to King.
+    pub fn rank_name(&self, rank: Rank) -> &'static str {
+        let pips = &self.insights.ranks;
+        match rank {
+            Rank::Ace => pips.ace,
+            Rank::Two => pips.two,
+            Rank::Three => pips.three,
+            Rank::Four => pips.four,
+            Rank::Five => pips.five,
+            Rank::Six => pips.six,
+            Rank::Seven => pips.seven,
+            Rank::Eight => pips.eight,
+            Rank::Nine => pips.nine,
+            Rank::Ten => pips.ten,
+            court => self.court_rank_name(court),
+        }
     }
 
-    pub fn toggle(&self) -> Self {
+    pub fn element_name(&self, element: Element) -> &'static str {
+        match element {
+            Element::Fire => self.elements.fire,
+            Element::Water => self.elements.water,
+            Element::Air => self.elements.air,
+            Element::Earth => self.elements.earth,
+        }
+    }
+
+    pub fn answer_name(&self, answer: Answer) -> &'static str {
+        match answer {
+            Answer::Yes => self.yes_no.yes,
+            Answer::No => self.yes_no.no,
+            Answer::Maybe => self.yes_no.maybe,
+        }
+    }
+
+    /// Localized `(title, prompt)` for a spread position.
+    pub fn position_text(&self, position: &'static SpreadPosition) -> (&'static str, &'static str) {
+        match self.positions.iter().find(|(id, _)| *id == position.id) {
+            Some((_, text)) => (text.title, text.prompt),
+            None => (position.title, position.prompt),
+        }
+    }
+}
+
+/// Which way a language's text runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(dead_code)] // `Rtl` is picked by `locales.json`.
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    /// Value for the HTML `dir` attribute.
+    pub fn as_str(self) -> &'static str {
         match self {
-            Language::English => Language::Ukrainian,
-            Language::Ukrainian => Language::English,
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
         }
     }
 }
 
+/// A language in the [`LOCALES`] registry, which `build.rs` generates from
+/// every `translations/<code>.json` and its metadata in `locales.json`.
+#[derive(Debug)]
+pub struct Locale {
+    pub code: &'static str,
+    /// Name of the language in itself, e.g. "Українська".
+    pub name: &'static str,
+    pub flag: &'static str,
+    pub direction: Direction,
+    /// Other codes that select this language, e.g. `uk` for `ua`.
+    pub aliases: &'static [&'static str],
+    translations: &'static Translations,
+    card_translations: &'static CardTranslations,
+    combination_translations: &'static CombinationTranslations,
+    coverage: &'static TranslationCoverage,
+}
+
+/// A handle to one of the [`LOCALES`]; English by default.
+#[derive(Clone, Copy)]
+pub struct Language(&'static Locale);
+
+impl Language {
+    /// Every language the app ships, ordered by code.
+    pub fn all() -> impl Iterator<Item = Language> {
+        LOCALES.iter().map(Language)
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.0.code
+    }
+
+    /// Flag and native name, as the language picker shows it.
+    pub fn label(&self) -> String {
+        format!("{} {}", self.0.flag, self.0.name)
+            .trim()
+            .to_string()
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.0.direction
+    }
+
+    /// The language for a code such as `uk` or `pt-BR`: an exact match or
+    /// alias first, then the primary subtag, then English.
+    pub fn from_code(code: &str) -> Self {
+        let code = code.trim().to_lowercase().replace('_', "-");
+        let find = |code: &str| {
+            LOCALES.iter().find(|locale| {
+                locale.code.eq_ignore_ascii_case(code) || locale.aliases.contains(&code)
+            })
+        };
+        let primary = code.split('-').next().unwrap_or_default();
+        find(&code)
+            .or_else(|| find(primary))
+            .map(Language)
+            .unwrap_or_default()
+    }
+
+    pub fn translations(&self) -> &'static Translations {
+        self.0.translations
+    }
+
+    pub fn card_translations(&self) -> &'static CardTranslations {
+        self.0.card_translations
+    }
+
+    pub fn combination_translations(&self) -> &'static CombinationTranslations {
+        self.0.combination_translations
+    }
+
+    /// Which texts of this language fell back, for spotting gaps.
+    pub fn coverage(&self) -> &'static TranslationCoverage {
+        self.0.coverage
+    }
+}
+
 impl Default for Language {
     fn default() -> Self {
-        Language::English
+        let english = LOCALES.iter().find(|locale| locale.code == "en");
+        Language(english.unwrap_or(&LOCALES[0]))
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.code == other.0.code
+    }
+}
+
+impl Eq for Language {}
+
+impl fmt::Debug for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Language").field(&self.0.code).finish()
     }
 }
 
-// Helper function to format copied message
-pub fn format_copied_message(t: &Translations, count: usize) -> String {
-    let plural = if count == 1 { "" } else { "s" };
-    t.feedback.copied
-        .replace("{count}", &count.to_string())
-        .replace("{plural}", plural)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::CARDS;
+
+    #[test]
+    fn every_card_has_text_in_every_language() {
+        for language in Language::all() {
+            let texts = language.card_translations();
+            for card in CARDS {
+                let text = texts.get(card);
+                assert!(!text.name.is_empty(), "{} in {language:?}", card.slug);
+                assert!(!text.upright.is_empty() && !text.reversed.is_empty());
+            }
+        }
+        let fool = CARDS.iter().find(|card| card.slug == "the-fool").unwrap();
+        assert_eq!(
+            Language::default().card_translations().get(fool).name,
+            fool.name
+        );
+    }
+
+    #[test]
+    fn english_never_falls_back() {
+        let coverage = Language::default().coverage();
+        assert!(coverage.keys > 0);
+        assert!(coverage.fallbacks.is_empty());
+    }
+
+    #[test]
+    fn codes_resolve_through_aliases_and_subtags() {
+        assert_eq!(Language::from_code("uk-UA").code(), "ua");
+        assert_eq!(Language::from_code("en_GB").code(), "en");
+        assert_eq!(Language::from_code("xx"), Language::default());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn pseudo_rtl_locale_keeps_message_syntax() {
+        let pseudo = Language::from_code("ar-XB");
+        assert_eq!(pseudo.direction(), Direction::Rtl);
+
+        let t = pseudo.translations();
+        let copied = t.format(t.feedback.copied, &[("count", 3usize.into())]);
+        assert!(copied.starts_with('\u{200F}'), "{copied:?}");
+        assert!(copied.contains("3 \u{202E}cards\u{202C}"), "{copied:?}");
+        assert_eq!(pseudo.coverage().fallbacks, &[]);
+    }
 }
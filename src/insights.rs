@@ -0,0 +1,207 @@
+//! Observations about a reading as a whole: how the cards split between the
+//! arcana, which suits dominate or are absent, how many cards are reversed,
+//! which ranks repeat and what the card numbers add up to.
+
+use crate::deck::{Arcana, DrawnCard, Orientation, Rank, Suit, TarotCard, CARDS};
+use crate::reading::Reading;
+
+/// A three-card draw always lacks at least one suit, so absent suits are only
+/// reported once a spread has this many cards.
+const MIN_CARDS_FOR_MISSING_SUITS: usize = 5;
+
+/// Summary of the spread cards of a [`Reading`]. Clarifiers and the
+/// significator are left out: they comment on the spread rather than belong to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadingInsights {
+    pub total: usize,
+    pub majors: usize,
+    pub minors: usize,
+    /// The suit with strictly more cards than any other, if it has at least two.
+    pub dominant_suit: Option<Suit>,
+    /// Suits with no card at all, in deck order.
+    pub missing_suits: Vec<Suit>,
+    pub reversed: usize,
+    /// Minor Arcana ranks that appear more than once, with their count.
+    pub repeated_ranks: Vec<(Rank, usize)>,
+    pub numerology: Numerology,
+}
+
+impl ReadingInsights {
+    /// Computes the insights of `reading`, or `None` if it has no cards yet.
+    pub fn new(reading: &Reading) -> Option<Self> {
+        Self::from_cards(reading.cards())
+    }
+
+    fn from_cards(cards: &[DrawnCard]) -> Option<Self> {
+        if cards.is_empty() {
+            return None;
+        }
+
+        let majors = cards.iter().filter(|card| card.card.is_major()).count();
+
+        let suit_counts = Suit::ALL.map(|suit| {
+            let count = cards
+                .iter()
+                .filter(|card| card.card.suit() == Some(suit))
+                .count();
+            (suit, count)
+        });
+        let most = suit_counts
+            .iter()
+            .map(|&(_, count)| count)
+            .max()
+            .unwrap_or(0);
+        let leaders: Vec<Suit> = suit_counts
+            .iter()
+            .filter(|&&(_, count)| count == most)
+            .map(|&(suit, _)| suit)
+            .collect();
+        let dominant_suit = match leaders[..] {
+            [suit] if most >= 2 => Some(suit),
+            _ => None,
+        };
+        let missing_suits = if cards.len() >= MIN_CARDS_FOR_MISSING_SUITS {
+            suit_counts
+                .iter()
+                .filter(|&&(_, count)| count == 0)
+                .map(|&(suit, _)| suit)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut repeated_ranks: Vec<(Rank, usize)> = Vec::new();
+        for card in cards {
+            if let Arcana::Minor { rank, .. } = card.card.arcana {
+                match repeated_ranks.iter_mut().find(|(seen, _)| *seen == rank) {
+                    Some((_, count)) => *count += 1,
+                    None => repeated_ranks.push((rank, 1)),
+                }
+            }
+        }
+        repeated_ranks.retain(|&(_, count)| count > 1);
+        repeated_ranks.sort_unstable();
+
+        Some(Self {
+            total: cards.len(),
+            majors,
+            minors: cards.len() - majors,
+            dominant_suit,
+            missing_suits,
+            reversed: cards
+                .iter()
+                .filter(|card| card.orientation == Orientation::Reversed)
+                .count(),
+            repeated_ranks,
+            numerology: Numerology::of(cards),
+        })
+    }
+
+    /// Share of reversed cards, rounded to a whole percent.
+    pub fn reversed_percent(&self) -> usize {
+        (self.reversed * 100 + self.total / 2) / self.total
+    }
+}
+
+/// The card numbers of a reading added up and reduced to a single digit by
+/// summing their digits, as in 1 + 13 + 20 = 34 → 3 + 4 = 7.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Numerology {
+    pub total: u32,
+    pub reduced: u8,
+}
+
+impl Numerology {
+    fn of(cards: &[DrawnCard]) -> Self {
+        let total: u32 = cards.iter().map(|card| u32::from(card.card.number())).sum();
+
+        let mut reduced = total;
+        while reduced > 9 {
+            reduced = digit_sum(reduced);
+        }
+
+        Self {
+            total,
+            reduced: reduced as u8,
+        }
+    }
+
+    /// The Major Arcana card carrying the reduced number, from The Fool (0)
+    /// to The Hermit (9).
+    pub fn card(&self) -> Option<&'static TarotCard> {
+        CARDS.iter().find(|card| {
+            card.arcana
+                == Arcana::Major {
+                    number: self.reduced,
+                }
+        })
+    }
+}
+
+fn digit_sum(mut value: u32) -> u32 {
+    let mut sum = 0;
+    while value > 0 {
+        sum += value % 10;
+        value /= 10;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::drawn;
+
+    #[test]
+    fn empty_reading_has_no_insights() {
+        assert_eq!(ReadingInsights::new(&Reading::empty()), None);
+    }
+
+    #[test]
+    fn counts_arcana_suits_reversals_and_repeats() {
+        let cards = drawn(&[
+            ("the-tower", Orientation::Reversed),
+            ("ace-of-cups", Orientation::Upright),
+            ("three-of-cups", Orientation::Reversed),
+            ("ace-of-wands", Orientation::Upright),
+            ("ace-of-swords", Orientation::Upright),
+        ]);
+        let insights = ReadingInsights::from_cards(&cards).expect("insights");
+
+        assert_eq!((insights.majors, insights.minors), (1, 4));
+        assert_eq!(insights.dominant_suit, Some(Suit::Cups));
+        assert_eq!(insights.missing_suits, [Suit::Pentacles]);
+        assert_eq!(insights.reversed_percent(), 40);
+        assert_eq!(insights.repeated_ranks, [(Rank::Ace, 3)]);
+    }
+
+    #[test]
+    fn ties_and_small_spreads_report_no_dominant_or_missing_suit() {
+        let cards = drawn(&[
+            ("two-of-cups", Orientation::Upright),
+            ("two-of-wands", Orientation::Upright),
+            ("the-sun", Orientation::Upright),
+        ]);
+        let insights = ReadingInsights::from_cards(&cards).expect("insights");
+
+        assert_eq!(insights.dominant_suit, None);
+        assert!(insights.missing_suits.is_empty());
+    }
+
+    #[test]
+    fn numerology_reduces_to_a_major_arcana_card() {
+        // The Magician (1) + King of Cups (14) + Judgement (20) = 35 → 8.
+        let cards = drawn(&[
+            ("the-magician", Orientation::Upright),
+            ("king-of-cups", Orientation::Upright),
+            ("judgement", Orientation::Upright),
+        ]);
+        let numerology = ReadingInsights::from_cards(&cards)
+            .expect("insights")
+            .numerology;
+
+        assert_eq!(numerology.total, 35);
+        assert_eq!(numerology.reduced, 8);
+        assert_eq!(numerology.card().map(|card| card.slug), Some("strength"));
+    }
+}
@@ -0,0 +1,6 @@
+//! The parts of the app that need no browser: the deck, the daily seed and
+//! the reading payload the mini app sends to the bot. The bot reuses them.
+
+pub mod daily;
+pub mod deck;
+pub mod payload;
@@ -1,11 +1,21 @@
 mod app;
-mod deck;
+mod backend;
+mod combinations;
+mod dignity;
 mod feedback;
+#[cfg(test)]
+mod fixtures;
+mod history;
 mod i18n;
+mod insights;
+mod message;
+mod oracle;
 mod reading;
 mod telegram;
 mod ui;
 
+use tg_tarot_app::{daily, deck, payload};
+
 fn main() {
     yew::Renderer::<app::App>::new().render();
 }
@@ -0,0 +1,345 @@
+//! Formatting of translated messages.
+//!
+//! Messages use a small subset of ICU MessageFormat: `{name}` inserts an
+//! argument, and `{count, plural, one {# card} other {# cards}}` picks a
+//! branch by the CLDR plural category of `count`, with `#` standing for the
+//! number. `=0 {…}` style branches match an exact value first, and `other`
+//! is the branch of last resort.
+
+use std::fmt;
+
+/// CLDR plural categories, see
+/// <https://cldr.unicode.org/index/cldr-spec/plural-rules>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    fn keyword(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// The CLDR cardinal rule sets for whole numbers, grouped by the languages
+/// that share them. `locales.json` picks one per language, so variants no
+/// shipped language uses yet are still live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(dead_code)]
+pub enum PluralRules {
+    /// No plural forms: Chinese, Japanese, Korean, Thai, Vietnamese.
+    None,
+    /// `one` for 1: English, German, Dutch, Italian, Spanish, Swedish.
+    #[default]
+    OneOther,
+    /// `one` for 0 and 1: French, Brazilian Portuguese.
+    French,
+    /// `one`, `few`, `many` by the last digits: Ukrainian, Russian, Belarusian.
+    EastSlavic,
+    /// `one` for 1, then `few` and `many` by the last digits: Polish.
+    Polish,
+    /// `one` for 1, `few` for 2–4: Czech, Slovak.
+    Czech,
+    /// `one`, `two`, otherwise `other`: Hebrew.
+    Hebrew,
+    /// All six categories: Arabic.
+    Arabic,
+}
+
+impl PluralRules {
+    pub fn category(self, n: u64) -> PluralCategory {
+        let (n10, n100) = (n % 10, n % 100);
+        match self {
+            PluralRules::None => PluralCategory::Other,
+            PluralRules::OneOther => match n {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            PluralRules::French => match n {
+                0 | 1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            PluralRules::EastSlavic => {
+                if n10 == 1 && n100 != 11 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            PluralRules::Polish => {
+                if n == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            PluralRules::Czech => match n {
+                1 => PluralCategory::One,
+                2..=4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            PluralRules::Hebrew => match n {
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ => PluralCategory::Other,
+            },
+            PluralRules::Arabic => match (n, n100) {
+                (0, _) => PluralCategory::Zero,
+                (1, _) => PluralCategory::One,
+                (2, _) => PluralCategory::Two,
+                (_, 3..=10) => PluralCategory::Few,
+                (_, 11..=99) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+        }
+    }
+}
+
+/// The value of a named message argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arg<'a> {
+    Number(u64),
+    Text(&'a str),
+}
+
+impl fmt::Display for Arg<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arg::Number(n) => write!(f, "{n}"),
+            Arg::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl From<u64> for Arg<'_> {
+    fn from(n: u64) -> Self {
+        Arg::Number(n)
+    }
+}
+
+impl From<usize> for Arg<'_> {
+    fn from(n: usize) -> Self {
+        Arg::Number(n as u64)
+    }
+}
+
+impl From<u32> for Arg<'_> {
+    fn from(n: u32) -> Self {
+        Arg::Number(n.into())
+    }
+}
+
+impl From<u8> for Arg<'_> {
+    fn from(n: u8) -> Self {
+        Arg::Number(n.into())
+    }
+}
+
+impl<'a> From<&'a str> for Arg<'a> {
+    fn from(text: &'a str) -> Self {
+        Arg::Text(text)
+    }
+}
+
+impl<'a> From<&'a String> for Arg<'a> {
+    fn from(text: &'a String) -> Self {
+        Arg::Text(text)
+    }
+}
+
+/// Formats `message` with the named `args`, choosing plural branches by
+/// `rules`. Unknown arguments and malformed braces are left as written, so a
+/// broken translation still shows something readable.
+pub fn format(message: &str, rules: PluralRules, args: &[(&str, Arg)]) -> String {
+    let mut out = String::with_capacity(message.len());
+    write_message(message, rules, args, None, &mut out);
+    out
+}
+
+/// Writes `message`; `number` replaces `#` inside a plural branch.
+fn write_message(
+    message: &str,
+    rules: PluralRules,
+    args: &[(&str, Arg)],
+    number: Option<u64>,
+    out: &mut String,
+) {
+    let mut rest = message;
+    while let Some(start) = rest.find(['{', '#']) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with('#') {
+            match number {
+                Some(n) => out.push_str(&n.to_string()),
+                None => out.push('#'),
+            }
+            rest = &rest[1..];
+            continue;
+        }
+
+        let Some(end) = closing_brace(rest) else {
+            break;
+        };
+        let argument = &rest[1..end];
+        if !write_argument(argument, rules, args, out) {
+            out.push_str(&rest[..=end]);
+        }
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+}
+
+/// Writes one `{…}` argument; returns `false` if it cannot be resolved.
+fn write_argument(
+    argument: &str,
+    rules: PluralRules,
+    args: &[(&str, Arg)],
+    out: &mut String,
+) -> bool {
+    let mut parts = argument.splitn(3, ',').map(str::trim);
+    let name = parts.next().unwrap_or_default();
+    let Some(value) = args
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| *value)
+    else {
+        return false;
+    };
+
+    match (parts.next(), parts.next(), value) {
+        (None, _, value) => {
+            out.push_str(&value.to_string());
+            true
+        }
+        (Some("plural"), Some(branches), Arg::Number(n)) => {
+            match select_branch(branches, rules.category(n), n) {
+                Some(branch) => write_message(branch, rules, args, Some(n), out),
+                None => out.push_str(&n.to_string()),
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Picks the branch for `n` from `=2 {…} one {…} other {…}`: an exact match
+/// first, then `category`, then `other`.
+fn select_branch(branches: &str, category: PluralCategory, n: u64) -> Option<&str> {
+    let mut exact = None;
+    let mut by_category = None;
+    let mut other = None;
+
+    let mut rest = branches.trim_start();
+    while let Some(open) = rest.find('{') {
+        let selector = rest[..open].trim();
+        let end = open + closing_brace(&rest[open..])?;
+        let branch = &rest[open + 1..end];
+
+        if selector
+            .strip_prefix('=')
+            .and_then(|value| value.parse().ok())
+            == Some(n)
+        {
+            exact = exact.or(Some(branch));
+        } else if selector == category.keyword() {
+            by_category = by_category.or(Some(branch));
+        } else if selector == "other" {
+            other = other.or(Some(branch));
+        }
+        rest = rest[end + 1..].trim_start();
+    }
+
+    exact.or(by_category).or(other)
+}
+
+/// Byte index of the `}` matching the `{` that `text` starts with.
+fn closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COPIED_UA: &str =
+        "Скопійовано {count, plural, one {# карту} few {# карти} many {# карт} other {# карти}}";
+
+    #[test]
+    fn east_slavic_categories_follow_the_last_digits() {
+        let rules = PluralRules::EastSlavic;
+        let expected = [
+            (1, PluralCategory::One),
+            (2, PluralCategory::Few),
+            (5, PluralCategory::Many),
+            (11, PluralCategory::Many),
+            (12, PluralCategory::Many),
+            (21, PluralCategory::One),
+            (22, PluralCategory::Few),
+            (111, PluralCategory::Many),
+        ];
+        for (n, category) in expected {
+            assert_eq!(rules.category(n), category, "{n}");
+        }
+    }
+
+    #[test]
+    fn plural_branches_and_named_arguments() {
+        let format_ua =
+            |n: usize| format(COPIED_UA, PluralRules::EastSlavic, &[("count", n.into())]);
+        assert_eq!(format_ua(1), "Скопійовано 1 карту");
+        assert_eq!(format_ua(3), "Скопійовано 3 карти");
+        assert_eq!(format_ua(10), "Скопійовано 10 карт");
+
+        let message = "{count, plural, =0 {Nothing} one {# card of {suit}} other {# cards}}";
+        let format_en = |n: usize| {
+            format(
+                message,
+                PluralRules::OneOther,
+                &[("count", n.into()), ("suit", "Cups".into())],
+            )
+        };
+        assert_eq!(format_en(0), "Nothing");
+        assert_eq!(format_en(1), "1 card of Cups");
+        assert_eq!(format_en(7), "7 cards");
+    }
+
+    #[test]
+    fn unknown_arguments_are_left_as_written() {
+        let text = format(
+            "{missing} · {count, plural, one {#}}",
+            PluralRules::OneOther,
+            &[],
+        );
+        assert_eq!(text, "{missing} · {count, plural, one {#}}");
+    }
+}
@@ -0,0 +1,96 @@
+//! The Yes/No oracle: every card answers yes, no or maybe (see
+//! [`crate::deck::YesNo`]) and the answers are tallied into a verdict.
+
+use std::cmp::Ordering;
+
+use crate::deck::{Answer, DrawnCard};
+use crate::reading::Reading;
+
+/// The tallied answer of a Yes/No reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Verdict {
+    pub answer: Answer,
+    pub yes: usize,
+    pub no: usize,
+    pub maybe: usize,
+}
+
+impl Verdict {
+    /// The verdict of a Yes/No reading; `None` for other spreads or before a draw.
+    pub fn of_reading(reading: &Reading) -> Option<Verdict> {
+        reading.spread().filter(|spread| spread.is_yes_no())?;
+        Self::tally(reading.cards())
+    }
+
+    /// "Maybe" cards abstain: the verdict is whichever of yes and no has more
+    /// cards, and maybe on a tie.
+    fn tally(cards: &[DrawnCard]) -> Option<Verdict> {
+        if cards.is_empty() {
+            return None;
+        }
+
+        let count = |answer| cards.iter().filter(|card| card.answer() == answer).count();
+        let (yes, no, maybe) = (count(Answer::Yes), count(Answer::No), count(Answer::Maybe));
+        let answer = match yes.cmp(&no) {
+            Ordering::Greater => Answer::Yes,
+            Ordering::Less => Answer::No,
+            Ordering::Equal => Answer::Maybe,
+        };
+
+        Some(Verdict {
+            answer,
+            yes,
+            no,
+            maybe,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::{Deck, Orientation, ReversalPolicy, SPREADS, YES_NO_SPREADS};
+    use crate::fixtures::drawn;
+
+    #[test]
+    fn orientation_changes_the_answer() {
+        let sun = drawn(&[
+            ("the-sun", Orientation::Upright),
+            ("the-sun", Orientation::Reversed),
+        ]);
+        assert_eq!(sun[0].answer(), Answer::Yes);
+        assert_eq!(sun[1].answer(), Answer::No);
+    }
+
+    #[test]
+    fn majority_decides_and_maybe_abstains() {
+        let cards = drawn(&[
+            ("the-tower", Orientation::Upright),
+            ("the-moon", Orientation::Upright),
+            ("the-star", Orientation::Upright),
+        ]);
+        let verdict = Verdict::tally(&cards).expect("verdict");
+        assert_eq!((verdict.yes, verdict.no, verdict.maybe), (1, 1, 1));
+        assert_eq!(verdict.answer, Answer::Maybe);
+
+        let cards = drawn(&[
+            ("the-world", Orientation::Upright),
+            ("the-hermit", Orientation::Upright),
+            ("ten-of-cups", Orientation::Upright),
+        ]);
+        assert_eq!(Verdict::tally(&cards).expect("verdict").answer, Answer::Yes);
+    }
+
+    #[test]
+    fn only_yes_no_spreads_have_a_verdict() {
+        let deal = |spread| {
+            let deal = Deck::standard()
+                .draw_seeded(spread, ReversalPolicy::default(), 3)
+                .expect("draw");
+            Reading::new(spread, 3, ReversalPolicy::default(), deal)
+        };
+
+        assert!(Verdict::of_reading(&deal(&YES_NO_SPREADS[1])).is_some());
+        assert!(Verdict::of_reading(&deal(&SPREADS[1])).is_none());
+    }
+}
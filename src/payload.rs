@@ -0,0 +1,313 @@
+//! The reading the mini app sends back to the bot with `WebApp.sendData`.
+//!
+//! Telegram delivers at most 4096 bytes, so the payload is one compact line
+//! rather than JSON. It has six `|`-separated fields:
+//!
+//! ```text
+//! 2|three|en|00952e|3d|Will the move go well?
+//! ```
+//!
+//! 1. the format version, [`PAYLOAD_VERSION`];
+//! 2. the spread id;
+//! 3. the locale code the reading was shown in;
+//! 4. the cards in position order, two hex digits each: the deck index, plus
+//!    `0x80` when the card is reversed;
+//! 5. the significator's deck index in two hex digits, or `-` without one;
+//! 6. the question, possibly empty. It is the last field, so it may contain `|`.
+//!
+//! The bot decodes it with [`ReadingPayload::decode`].
+
+use std::error::Error;
+use std::fmt;
+
+use crate::deck::{DrawnCard, Orientation, Spread, TarotCard, CARDS};
+
+/// The payload format this build writes. Decoding rejects any other version.
+pub const PAYLOAD_VERSION: u8 = 2;
+
+/// The most bytes `WebApp.sendData` accepts.
+pub const MAX_PAYLOAD_BYTES: usize = 4096;
+
+const SEPARATOR: char = '|';
+const REVERSED: u8 = 0x80;
+const NO_SIGNIFICATOR: &str = "-";
+
+/// A finished reading on its way from the mini app to the bot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadingPayload {
+    pub spread: &'static Spread,
+    /// One card per spread position, in position order.
+    pub cards: Vec<DrawnCard>,
+    /// The card chosen to stand for the querent, always upright.
+    pub significator: Option<&'static TarotCard>,
+    /// The question of a Yes/No reading.
+    pub question: Option<String>,
+    /// Code of the language the reading was shown in, e.g. `ua`.
+    pub locale: String,
+}
+
+/// Why a payload could not be encoded or decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadError {
+    /// The encoded reading is over [`MAX_PAYLOAD_BYTES`].
+    TooLarge {
+        bytes: usize,
+    },
+    /// The payload was written by a different format version.
+    UnsupportedVersion(String),
+    /// The payload does not have the expected fields.
+    Malformed,
+    UnknownSpread(String),
+    UnknownCard(u8),
+    /// The payload has more or fewer cards than the spread has positions.
+    CardCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for PayloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PayloadError::TooLarge { bytes } => write!(
+                f,
+                "The reading takes {bytes} bytes, but Telegram accepts {MAX_PAYLOAD_BYTES}."
+            ),
+            PayloadError::UnsupportedVersion(version) => {
+                write!(f, "Payload version {version} is not supported.")
+            }
+            PayloadError::Malformed => write!(f, "The payload is malformed."),
+            PayloadError::UnknownSpread(id) => write!(f, "Unknown spread `{id}`."),
+            PayloadError::UnknownCard(index) => write!(f, "Unknown card {index:#04x}."),
+            PayloadError::CardCount { expected, found } => write!(
+                f,
+                "The spread has {expected} positions, but the payload has {found} cards."
+            ),
+        }
+    }
+}
+
+impl Error for PayloadError {}
+
+impl ReadingPayload {
+    /// Encodes the payload, or reports that it is too large for `sendData`.
+    pub fn encode(&self) -> Result<String, PayloadError> {
+        let encoded = self.to_line();
+        if encoded.len() > MAX_PAYLOAD_BYTES {
+            return Err(PayloadError::TooLarge {
+                bytes: encoded.len(),
+            });
+        }
+        Ok(encoded)
+    }
+
+    /// The encoded line without the `sendData` size limit, e.g. for storage.
+    pub fn to_line(&self) -> String {
+        let cards: String = self
+            .cards
+            .iter()
+            .map(|card| format!("{:02x}", card_code(card.card, card.orientation)))
+            .collect();
+        let significator = match self.significator {
+            Some(card) => format!("{:02x}", card.index),
+            None => NO_SIGNIFICATOR.to_string(),
+        };
+        // The locale is not the last field, so it must not contain the separator.
+        let locale = self.locale.replace(SEPARATOR, "");
+        format!(
+            "{PAYLOAD_VERSION}{SEPARATOR}{}{SEPARATOR}{locale}{SEPARATOR}{cards}{SEPARATOR}{significator}{SEPARATOR}{}",
+            self.spread.id,
+            self.question.as_deref().unwrap_or_default(),
+        )
+    }
+
+    /// Decodes what [`ReadingPayload::encode`] wrote. Each card gets the
+    /// spread position it was dealt into back.
+    pub fn decode(data: &str) -> Result<Self, PayloadError> {
+        if data.len() > MAX_PAYLOAD_BYTES {
+            return Err(PayloadError::TooLarge { bytes: data.len() });
+        }
+        Self::from_line(data)
+    }
+
+    /// Decodes what [`ReadingPayload::to_line`] wrote, of any length.
+    pub fn from_line(data: &str) -> Result<Self, PayloadError> {
+        let mut fields = data.splitn(6, SEPARATOR);
+        let version = fields.next().unwrap_or_default();
+        if version != PAYLOAD_VERSION.to_string() {
+            return Err(PayloadError::UnsupportedVersion(version.to_string()));
+        }
+        let (Some(spread), Some(locale), Some(cards), Some(significator), Some(question)) = (
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+        ) else {
+            return Err(PayloadError::Malformed);
+        };
+
+        let spread =
+            Spread::by_id(spread).ok_or_else(|| PayloadError::UnknownSpread(spread.to_string()))?;
+        if !cards.is_ascii() || cards.len() % 2 != 0 {
+            return Err(PayloadError::Malformed);
+        }
+        let found = cards.len() / 2;
+        if found != spread.card_count() {
+            return Err(PayloadError::CardCount {
+                expected: spread.card_count(),
+                found,
+            });
+        }
+
+        let cards = spread
+            .positions
+            .iter()
+            .enumerate()
+            .map(|(slot, position)| {
+                let code = u8::from_str_radix(&cards[slot * 2..slot * 2 + 2], 16)
+                    .map_err(|_| PayloadError::Malformed)?;
+                let (card, orientation) = card_from_code(code)?;
+                Ok(DrawnCard {
+                    card,
+                    orientation,
+                    position,
+                })
+            })
+            .collect::<Result<_, PayloadError>>()?;
+
+        let significator = match significator {
+            NO_SIGNIFICATOR => None,
+            hex if hex.len() == 2 => {
+                let index = u8::from_str_radix(hex, 16).map_err(|_| PayloadError::Malformed)?;
+                Some(card_at(index)?)
+            }
+            _ => return Err(PayloadError::Malformed),
+        };
+
+        Ok(Self {
+            spread,
+            cards,
+            significator,
+            question: (!question.is_empty()).then(|| question.to_string()),
+            locale: locale.to_string(),
+        })
+    }
+}
+
+/// How the payload writes a card: its deck index, plus `0x80` when reversed.
+pub fn card_code(card: &TarotCard, orientation: Orientation) -> u8 {
+    match orientation {
+        Orientation::Upright => card.index,
+        Orientation::Reversed => card.index | REVERSED,
+    }
+}
+
+/// Reads what [`card_code`] wrote.
+pub fn card_from_code(code: u8) -> Result<(&'static TarotCard, Orientation), PayloadError> {
+    let orientation = if code & REVERSED == 0 {
+        Orientation::Upright
+    } else {
+        Orientation::Reversed
+    };
+    Ok((card_at(code & !REVERSED)?, orientation))
+}
+
+fn card_at(index: u8) -> Result<&'static TarotCard, PayloadError> {
+    CARDS
+        .get(usize::from(index))
+        .ok_or(PayloadError::UnknownCard(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::{Deck, ReversalPolicy, SPREADS, YES_NO_SPREADS};
+
+    #[test]
+    fn payload_round_trips_through_the_compact_encoding() {
+        let spread = &YES_NO_SPREADS[1];
+        let deal = Deck::standard()
+            .draw_seeded(spread, ReversalPolicy::default(), 7)
+            .unwrap();
+        let payload = ReadingPayload {
+            spread,
+            cards: deal.cards,
+            significator: None,
+            question: Some("Stay | or go?".to_string()),
+            locale: "ua".to_string(),
+        };
+
+        let encoded = payload.encode().unwrap();
+        assert!(encoded.starts_with("2|yes-no-three|ua|"), "{encoded}");
+        assert!(encoded.ends_with("|-|Stay | or go?"), "{encoded}");
+        assert_eq!(ReadingPayload::decode(&encoded), Ok(payload));
+
+        let queen = CARDS
+            .iter()
+            .find(|card| card.slug == "queen-of-cups")
+            .unwrap();
+        let spread = &SPREADS[0];
+        let deal = Deck::standard()
+            .draw_seeded(spread, ReversalPolicy::default(), 7)
+            .unwrap();
+        let payload = ReadingPayload {
+            spread,
+            cards: deal.cards,
+            significator: Some(queen),
+            question: None,
+            locale: "en".to_string(),
+        };
+
+        let encoded = payload.encode().unwrap();
+        assert!(
+            encoded.ends_with(&format!("|{:02x}|", queen.index)),
+            "{encoded}"
+        );
+        assert_eq!(ReadingPayload::decode(&encoded), Ok(payload));
+    }
+
+    #[test]
+    fn oversized_and_foreign_payloads_are_rejected() {
+        let spread = Spread::by_id("single").unwrap();
+        let payload = ReadingPayload {
+            spread,
+            cards: Vec::new(),
+            significator: None,
+            question: Some("?".repeat(MAX_PAYLOAD_BYTES)),
+            locale: "en".to_string(),
+        };
+        assert!(matches!(
+            payload.encode(),
+            Err(PayloadError::TooLarge { .. })
+        ));
+
+        assert_eq!(
+            ReadingPayload::decode("1|single|en|00|"),
+            Err(PayloadError::UnsupportedVersion("1".to_string()))
+        );
+        assert_eq!(
+            ReadingPayload::decode("2|single|en|0000|-|"),
+            Err(PayloadError::CardCount {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            ReadingPayload::decode("2|single|en|4e|-|"),
+            Err(PayloadError::UnknownCard(0x4e))
+        );
+        assert_eq!(
+            ReadingPayload::decode("2|single|en|00|4e|"),
+            Err(PayloadError::UnknownCard(0x4e))
+        );
+        assert_eq!(
+            ReadingPayload::decode("2|single|en|00|queen|"),
+            Err(PayloadError::Malformed)
+        );
+        assert_eq!(
+            ReadingPayload::decode("2|single|en|00"),
+            Err(PayloadError::Malformed)
+        );
+    }
+}
@@ -1,17 +1,120 @@
-use crate::deck::DrawnCard;
+use crate::deck::{
+    Deal, Deck, DeckError, DeckFilter, DrawnCard, RemainingDeck, ReversalPolicy, Spread, TarotCard,
+};
+use crate::history::HistoryEntry;
+use crate::payload::ReadingPayload;
+
+/// An extra card drawn to shed light on one card of the spread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clarifier {
+    /// Index of the clarified card in [`Reading::cards`].
+    pub parent: usize,
+    pub card: DrawnCard,
+}
 
 #[derive(Clone, PartialEq, Default)]
 pub struct Reading {
+    spread: Option<&'static Spread>,
+    seed: Option<u64>,
+    reversals: ReversalPolicy,
+    significator: Option<&'static TarotCard>,
+    question: Option<String>,
+    /// The filter the cards were dealt from, leaving the significator aside.
+    filter: DeckFilter,
     cards: Vec<DrawnCard>,
+    clarifiers: Vec<Clarifier>,
+    remaining: RemainingDeck,
 }
 
 impl Reading {
     pub fn empty() -> Self {
-        Self { cards: Vec::new() }
+        Self::default()
+    }
+
+    /// Builds a reading from a deal made with [`crate::deck::Deck::draw_seeded`].
+    pub fn new(spread: &'static Spread, seed: u64, reversals: ReversalPolicy, deal: Deal) -> Self {
+        Self {
+            spread: Some(spread),
+            seed: Some(seed),
+            reversals,
+            significator: None,
+            question: None,
+            filter: DeckFilter::default(),
+            cards: deal.cards,
+            clarifiers: Vec::new(),
+            remaining: deal.remaining,
+        }
+    }
+
+    /// Rebuilds a reading saved in history. Further clarifiers come from the
+    /// cards of its deck filter that are not on the table yet, shuffled with
+    /// the reading's seed.
+    pub fn restore(saved: HistoryEntry) -> Self {
+        let reading = saved.reading;
+        let mut on_table = saved.filter.clone();
+        on_table.exclude.extend(
+            reading
+                .cards
+                .iter()
+                .chain(saved.clarifiers.iter().map(|clarifier| &clarifier.card))
+                .map(|card| card.card.slug)
+                .chain(reading.significator.map(|card| card.slug)),
+        );
+        Self {
+            spread: Some(reading.spread),
+            seed: Some(saved.seed),
+            reversals: saved.reversals,
+            significator: reading.significator,
+            question: reading.question,
+            filter: saved.filter,
+            remaining: Deck::standard()
+                .filter(&on_table)
+                .shuffle_seeded(saved.reversals, saved.seed),
+            cards: reading.cards,
+            clarifiers: saved.clarifiers,
+        }
+    }
+
+    /// Records the significator that was taken out of the deck before the deal.
+    pub fn with_significator(mut self, significator: Option<&'static TarotCard>) -> Self {
+        self.significator = significator;
+        self
+    }
+
+    /// Records the deck filter the cards were dealt from.
+    pub fn with_filter(mut self, filter: DeckFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Records the closed question asked in the Yes/No oracle.
+    pub fn with_question(mut self, question: String) -> Self {
+        self.question = Some(question);
+        self
+    }
+
+    pub fn spread(&self) -> Option<&'static Spread> {
+        self.spread
     }
 
-    pub fn from_cards(cards: Vec<DrawnCard>) -> Self {
-        Self { cards }
+    /// The seed that reproduces this reading, if it came from a draw.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// The reversal policy the cards were dealt with.
+    pub fn reversals(&self) -> ReversalPolicy {
+        self.reversals
+    }
+
+    /// The card chosen to stand for the querent, if any.
+    pub fn significator(&self) -> Option<&'static TarotCard> {
+        self.significator
+    }
+
+    /// The question of a Yes/No reading.
+    pub fn question(&self) -> Option<&str> {
+        self.question.as_deref()
     }
 
     pub fn cards(&self) -> &[DrawnCard] {
@@ -21,4 +124,95 @@ impl Reading {
     pub fn has_cards(&self) -> bool {
         !self.cards.is_empty()
     }
+
+    /// How many cards are on the table: the spread's and their clarifiers,
+    /// but not the significator.
+    pub fn card_count(&self) -> usize {
+        self.cards.len() + self.clarifiers.len()
+    }
+
+    /// Every clarifier drawn so far, oldest first.
+    pub fn clarifiers(&self) -> &[Clarifier] {
+        &self.clarifiers
+    }
+
+    /// Clarifiers drawn for the card at `parent`, oldest first.
+    pub fn clarifiers_for(&self, parent: usize) -> impl Iterator<Item = &DrawnCard> {
+        self.clarifiers
+            .iter()
+            .filter(move |clarifier| clarifier.parent == parent)
+            .map(|clarifier| &clarifier.card)
+    }
+
+    /// The spread cards, significator and question to send to the bot, tagged
+    /// with the `locale` they were shown in. `None` before the first draw.
+    pub fn payload(&self, locale: &str) -> Option<ReadingPayload> {
+        Some(ReadingPayload {
+            spread: self.spread?,
+            cards: self.cards.clone(),
+            significator: self.significator,
+            question: self.question.clone(),
+            locale: locale.to_string(),
+        })
+    }
+
+    /// The history entry that stores this reading, saved at `saved_at`.
+    /// `None` unless the reading came from a draw.
+    pub fn history_entry(&self, locale: &str, saved_at: u64) -> Option<HistoryEntry> {
+        Some(HistoryEntry {
+            saved_at,
+            reading: self.payload(locale)?,
+            seed: self.seed?,
+            reversals: self.reversals,
+            clarifiers: self.clarifiers.clone(),
+            filter: self.filter.clone(),
+        })
+    }
+
+    /// Draws the next card of the remaining deck as a clarifier for the card
+    /// at `parent`. The clarifier shares its parent's spread position.
+    pub fn draw_clarifier(&mut self, parent: usize) -> Result<(), DeckError> {
+        let parent_card = self.cards.get(parent).ok_or(DeckError::InvalidPosition)?;
+
+        let card = self.remaining.draw(parent_card.position)?;
+        self.clarifiers.push(Clarifier { parent, card });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::{ReversalPolicy, Spread, CARDS};
+
+    fn reading(spread_id: &str) -> Reading {
+        let spread = Spread::by_id(spread_id).unwrap();
+        let policy = spread.reversal_policy(ReversalPolicy::default());
+        let deal = Deck::standard().draw_seeded(spread, policy, 7).unwrap();
+        Reading::new(spread, 7, policy, deal)
+    }
+
+    #[test]
+    fn only_table_cards_are_counted() {
+        let significator = CARDS.iter().find(|card| card.slug == "the-fool");
+        let mut reading = reading("yes-no")
+            .with_question("Will it rain?".to_string())
+            .with_significator(significator);
+        assert_eq!(reading.card_count(), 1);
+
+        reading.draw_clarifier(0).unwrap();
+        assert_eq!(reading.card_count(), 2);
+    }
+
+    #[test]
+    fn clarifiers_need_a_card_to_clarify() {
+        let mut reading = reading("three");
+
+        assert_eq!(reading.draw_clarifier(3), Err(DeckError::InvalidPosition));
+        assert!(reading.clarifiers().is_empty());
+        assert_eq!(
+            Reading::empty().draw_clarifier(0),
+            Err(DeckError::InvalidPosition)
+        );
+    }
 }
@@ -1,6 +1,11 @@
+use std::error::Error;
+use std::fmt;
+
 use yew::prelude::*;
 
+use crate::history::{Storage, StorageError};
 use crate::i18n::Language;
+use crate::payload::{PayloadError, ReadingPayload, MAX_PAYLOAD_BYTES};
 
 #[cfg(target_arch = "wasm32")]
 use telegram_webapp_sdk::{
@@ -52,6 +57,18 @@ pub struct BackButtonState {
     pub visible: bool,
 }
 
+/// Declarative look of the Telegram MainButton.
+#[derive(Clone, PartialEq, Default)]
+pub struct MainButtonState {
+    pub visible: bool,
+    pub text: String,
+    /// Background colour; `None` leaves Telegram's current one.
+    pub color: Option<String>,
+    pub text_color: Option<String>,
+    /// Shows the spinner and ignores taps until the work is done.
+    pub progress: bool,
+}
+
 /// Tries to initialise the Telegram WebApp SDK and read theme colours.
 ///
 /// The function is safe to call in a regular browser – it falls back to the
@@ -126,8 +143,286 @@ pub fn use_back_button(state: BackButtonState, on_click: Callback<()>) {
     }
 }
 
+/// Synchronises the Telegram MainButton with the provided state and handler.
+#[hook]
+pub fn use_main_button(state: MainButtonState, on_click: Callback<()>) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use_effect_with(state, |state| {
+            sync_main_button(state);
+            || ()
+        });
+
+        // A new callback replaces the old one rather than joining it: dropping
+        // the previous guard unregisters its handler.
+        use_effect_with(on_click, |callback| {
+            let guard = register_main_button_handler(callback.clone());
+            move || drop(guard)
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (state, on_click);
+    }
+}
+
+/// Why the reading could not be copied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// Neither Telegram nor the browser offers a clipboard here.
+    Unavailable,
+    /// The browser refused or failed the copy command.
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    Failed,
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClipboardError::Unavailable => write!(f, "Clipboard is not available here."),
+            ClipboardError::Failed => write!(f, "Clipboard command failed."),
+        }
+    }
+}
+
+impl Error for ClipboardError {}
+
+/// Why the reading could not be sent to the chat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SendError {
+    /// The app is not running inside Telegram, or was not opened from a
+    /// keyboard button, the only launch that can send data.
+    Unavailable,
+    /// The reading does not fit in a `sendData` message.
+    TooLarge { bytes: usize },
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendError::Unavailable => write!(f, "Sending to the chat is not available here."),
+            SendError::TooLarge { bytes } => write!(
+                f,
+                "The reading takes {bytes} bytes, over Telegram's {MAX_PAYLOAD_BYTES}."
+            ),
+        }
+    }
+}
+
+impl Error for SendError {}
+
+/// Whether `WebApp.sendData` works in this launch. Telegram only allows it in
+/// an app opened from a keyboard button, the one launch whose `initData`
+/// carries no `query_id`.
+pub fn can_send_data() -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use js_sys::Reflect;
+        use wasm_bindgen::JsValue;
+
+        web_app_object()
+            .and_then(|web_app| Reflect::get(&web_app, &JsValue::from_str("initDataUnsafe")).ok())
+            .and_then(|init_data| Reflect::get(&init_data, &JsValue::from_str("query_id")).ok())
+            .is_some_and(|query_id| query_id.is_undefined())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    false
+}
+
+/// Sends the reading to the bot with `WebApp.sendData`. Telegram closes the
+/// mini app once the data is on its way.
+pub fn send_reading(payload: &ReadingPayload) -> Result<(), SendError> {
+    let data = payload.encode().map_err(|err| match err {
+        PayloadError::TooLarge { bytes } => SendError::TooLarge { bytes },
+        _ => SendError::Unavailable,
+    })?;
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let app = TelegramWebApp::instance().ok_or(SendError::Unavailable)?;
+        app.send_data(&data).map_err(|_| SendError::Unavailable)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = data;
+        Err(SendError::Unavailable)
+    }
+}
+
+/// Where the reading history is kept: Telegram CloudStorage when the client
+/// has it (Bot API 6.9 and later), the browser's `localStorage` otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserStorage {
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    Cloud,
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    Local,
+}
+
+impl BrowserStorage {
+    /// The best store available here, if any.
+    pub fn detect() -> Option<Self> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let cloud = TelegramWebApp::instance()
+                .and_then(|app| app.is_version_at_least("6.9").ok())
+                .unwrap_or(false);
+            if cloud {
+                return Some(BrowserStorage::Cloud);
+            }
+            local_storage().map(|_| BrowserStorage::Local)
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        None
+    }
+}
+
+impl Storage for BrowserStorage {
+    async fn get_items(&self, keys: &[String]) -> Result<Vec<Option<String>>, StorageError> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use js_sys::{Array, Reflect};
+            use wasm_bindgen::JsValue;
+
+            match self {
+                BrowserStorage::Cloud => {
+                    if keys.is_empty() {
+                        return Ok(Vec::new());
+                    }
+                    let names: Array = keys.iter().map(|key| JsValue::from_str(key)).collect();
+                    let values = cloud_storage_call("getItems", &[names.into()]).await?;
+                    // CloudStorage answers an empty string for a key that is not set.
+                    Ok(keys
+                        .iter()
+                        .map(|key| {
+                            Reflect::get(&values, &JsValue::from_str(key))
+                                .ok()
+                                .and_then(|value| value.as_string())
+                                .filter(|value| !value.is_empty())
+                        })
+                        .collect())
+                }
+                BrowserStorage::Local => {
+                    let storage = local_storage().ok_or(StorageError::Unavailable)?;
+                    keys.iter()
+                        .map(|key| storage.get_item(key).map_err(|_| StorageError::Failed))
+                        .collect()
+                }
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = keys;
+            Err(StorageError::Unavailable)
+        }
+    }
+
+    async fn set_item(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::JsValue;
+
+            match self {
+                BrowserStorage::Cloud => {
+                    let args = [JsValue::from_str(key), JsValue::from_str(value)];
+                    cloud_storage_call("setItem", &args).await.map(|_| ())
+                }
+                BrowserStorage::Local => local_storage()
+                    .ok_or(StorageError::Unavailable)?
+                    .set_item(key, value)
+                    .map_err(|_| StorageError::Failed),
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (key, value);
+            Err(StorageError::Unavailable)
+        }
+    }
+
+    async fn remove_items(&self, keys: &[String]) -> Result<(), StorageError> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use js_sys::Array;
+            use wasm_bindgen::JsValue;
+
+            match self {
+                BrowserStorage::Cloud => {
+                    let names: Array = keys.iter().map(|key| JsValue::from_str(key)).collect();
+                    cloud_storage_call("removeItems", &[names.into()])
+                        .await
+                        .map(|_| ())
+                }
+                BrowserStorage::Local => {
+                    let storage = local_storage().ok_or(StorageError::Unavailable)?;
+                    keys.iter().try_for_each(|key| {
+                        storage.remove_item(key).map_err(|_| StorageError::Failed)
+                    })
+                }
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = keys;
+            Err(StorageError::Unavailable)
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Calls a `Telegram.WebApp.CloudStorage` method with `args` and waits for
+/// Telegram's `callback(error, result)`.
+#[cfg(target_arch = "wasm32")]
+async fn cloud_storage_call(
+    method: &str,
+    args: &[wasm_bindgen::JsValue],
+) -> Result<wasm_bindgen::JsValue, StorageError> {
+    use js_sys::{Array, Function, Promise, Reflect};
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_futures::JsFuture;
+
+    let web_app = web_app_object().ok_or(StorageError::Unavailable)?;
+    let storage = Reflect::get(&web_app, &JsValue::from_str("CloudStorage"))
+        .map_err(|_| StorageError::Unavailable)?;
+    let function = Reflect::get(&storage, &JsValue::from_str(method))
+        .ok()
+        .and_then(|function| function.dyn_into::<Function>().ok())
+        .ok_or(StorageError::Unavailable)?;
+
+    let args: Array = args.iter().collect();
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        let on_failure = reject.clone();
+        let callback = Closure::once_into_js(move |error: JsValue, result: JsValue| {
+            let _ = if error.is_null() || error.is_undefined() {
+                resolve.call1(&JsValue::NULL, &result)
+            } else {
+                reject.call1(&JsValue::NULL, &error)
+            };
+        });
+        args.push(&callback);
+        if let Err(err) = function.apply(&storage, &args) {
+            let _ = on_failure.call1(&JsValue::NULL, &err);
+        }
+    });
+    JsFuture::from(promise)
+        .await
+        .map_err(|_| StorageError::Failed)
+}
+
 /// Copies text to the clipboard using either the Telegram SDK or the DOM clipboard.
-pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
+pub fn copy_to_clipboard(text: &str) -> Result<(), ClipboardError> {
     #[cfg(target_arch = "wasm32")]
     {
         if try_telegram_clipboard(text) {
@@ -139,7 +434,7 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
     #[cfg(not(target_arch = "wasm32"))]
     {
         let _ = text;
-        Err("Clipboard is only available in a browser context.".into())
+        Err(ClipboardError::Unavailable)
     }
 }
 
@@ -161,39 +456,37 @@ fn try_telegram_clipboard(text: &str) -> bool {
 }
 
 #[cfg(target_arch = "wasm32")]
-fn dom_copy(text: &str) -> Result<(), String> {
+fn dom_copy(text: &str) -> Result<(), ClipboardError> {
     let document = document();
     let html_document = document
         .clone()
         .dyn_into::<HtmlDocument>()
-        .map_err(|_| "Unable to access HTML document".to_string())?;
+        .map_err(|_| ClipboardError::Unavailable)?;
     let textarea = document
         .create_element("textarea")
-        .map_err(|_| "Unable to access document".to_string())?
+        .map_err(|_| ClipboardError::Unavailable)?
         .dyn_into::<HtmlTextAreaElement>()
-        .map_err(|_| "Unable to create textarea".to_string())?;
+        .map_err(|_| ClipboardError::Unavailable)?;
     textarea.set_value(text);
     textarea
         .set_attribute("readonly", "")
-        .map_err(|_| "Unable to configure textarea".to_string())?;
+        .map_err(|_| ClipboardError::Unavailable)?;
     textarea.style().set_property("position", "absolute").ok();
     textarea.style().set_property("left", "-9999px").ok();
 
-    let body = document
-        .body()
-        .ok_or_else(|| "Unable to access document body".to_string())?;
+    let body = document.body().ok_or(ClipboardError::Unavailable)?;
     body.append_child(&textarea)
-        .map_err(|_| "Unable to append helper".to_string())?;
+        .map_err(|_| ClipboardError::Unavailable)?;
     textarea.select();
     let success = html_document
         .exec_command("copy")
-        .map_err(|_| "Clipboard command failed".to_string())?;
+        .map_err(|_| ClipboardError::Failed)?;
     body.remove_child(&textarea).ok();
 
     if success {
         Ok(())
     } else {
-        Err("Clipboard command rejected".into())
+        Err(ClipboardError::Failed)
     }
 }
 
@@ -223,6 +516,38 @@ fn register_back_button_handler(callback: Callback<()>) -> Option<EventHandle<dy
     app.set_back_button_callback(move || callback.emit(())).ok()
 }
 
+#[cfg(target_arch = "wasm32")]
+fn sync_main_button(state: &MainButtonState) {
+    let Some(app) = TelegramWebApp::instance() else {
+        return;
+    };
+    if !state.visible {
+        let _ = app.hide_main_button();
+        return;
+    }
+
+    let _ = app.set_main_button_text(&state.text);
+    if let Some(color) = &state.color {
+        let _ = app.set_main_button_color(color);
+    }
+    if let Some(color) = &state.text_color {
+        let _ = app.set_main_button_text_color(color);
+    }
+    if state.progress {
+        let _ = app.show_main_button_progress(false);
+    } else {
+        let _ = app.hide_main_button_progress();
+        let _ = app.enable_main_button();
+    }
+    let _ = app.show_main_button();
+}
+
+#[cfg(target_arch = "wasm32")]
+fn register_main_button_handler(callback: Callback<()>) -> Option<EventHandle<dyn FnMut()>> {
+    let app = TelegramWebApp::instance()?;
+    app.set_main_button_callback(move || callback.emit(())).ok()
+}
+
 /// Detects the user's language from Telegram or browser
 pub fn detect_language() -> Language {
     #[cfg(target_arch = "wasm32")]
@@ -236,8 +561,8 @@ pub fn detect_language() -> Language {
         if let Some(window) = web_sys::window() {
             let navigator = window.navigator();
             if let Some(lang) = navigator.language() {
-                let code = lang.split('-').next().unwrap_or("en");
-                return Language::from_code(code);
+                // The full tag, so regional and pseudo-locales can match.
+                return Language::from_code(&lang);
             }
         }
     }
@@ -245,14 +570,51 @@ pub fn detect_language() -> Language {
     Language::default()
 }
 
+/// The Telegram user id from `initDataUnsafe`, when running inside Telegram.
+///
+/// The value is not verified, so only use it for cosmetic, per-user behaviour
+/// such as the daily card.
+pub fn telegram_user_id() -> Option<i64> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        init_data_user_field("id")?.as_f64().map(|id| id as i64)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    None
+}
+
+/// The raw, signed `WebApp.initData` of this launch, which the backend
+/// verifies (see `src/backend.rs`). `None` outside Telegram.
+pub fn init_data() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use js_sys::Reflect;
+        use wasm_bindgen::JsValue;
+
+        let web_app = web_app_object()?;
+        Reflect::get(&web_app, &JsValue::from_str("initData"))
+            .ok()?
+            .as_string()
+            .filter(|init_data| !init_data.is_empty())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    None
+}
+
 #[cfg(target_arch = "wasm32")]
 fn get_telegram_language() -> Option<String> {
+    init_data_user_field("language_code")?.as_string()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn init_data_user_field(field: &str) -> Option<wasm_bindgen::JsValue> {
     use js_sys::Reflect;
     use wasm_bindgen::JsValue;
 
     let web_app = web_app_object()?;
     let init_data_unsafe = Reflect::get(&web_app, &JsValue::from_str("initDataUnsafe")).ok()?;
     let user = Reflect::get(&init_data_unsafe, &JsValue::from_str("user")).ok()?;
-    let lang = Reflect::get(&user, &JsValue::from_str("language_code")).ok()?;
-    lang.as_string()
+    Reflect::get(&user, &JsValue::from_str(field)).ok()
 }
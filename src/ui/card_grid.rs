@@ -1,25 +1,31 @@
 use yew::prelude::*;
 
-use crate::deck::{DrawnCard, Orientation};
+use crate::deck::{DrawnCard, Orientation, ReversalPolicy, Suit, TarotCard, SIGNIFICATOR_POSITION};
+use crate::dignity::Dignity;
 use crate::i18n::{CardTranslations, Translations};
+use crate::reading::Reading;
 
-/// Displays the drawn cards in a responsive grid.
+/// Displays the drawn cards in a responsive grid, labelled by spread position.
 #[derive(Properties, PartialEq)]
 pub struct CardGridProps {
-    pub cards: Vec<DrawnCard>,
-    pub translations: Translations,
-    pub card_translations: CardTranslations,
+    pub reading: Reading,
+    /// The reversal policy selected for the next draw, described in the empty state.
+    pub reversals: ReversalPolicy,
+    /// Draws a clarifier for the card at the given index.
+    pub on_clarify: Callback<usize>,
+    pub translations: &'static Translations,
+    pub card_translations: &'static CardTranslations,
 }
 
 #[function_component(CardGrid)]
 pub fn card_grid(props: &CardGridProps) -> Html {
-    let cards = &props.cards;
+    let cards = props.reading.cards();
     let t = &props.translations;
     let flipped = use_state(Vec::<bool>::new);
 
     {
         let flipped = flipped.clone();
-        let cards = cards.clone();
+        let cards = cards.to_vec();
         use_effect_with(cards, move |cards| {
             flipped.set(vec![false; cards.len()]);
         });
@@ -28,7 +34,7 @@ pub fn card_grid(props: &CardGridProps) -> Html {
     if cards.is_empty() {
         return html! {
             <section class="empty-state">
-                <h2>{ &t.empty.title }</h2>
+                <h2>{ t.empty_title(props.reversals) }</h2>
                 <p>{ &t.empty.subtitle }</p>
             </section>
         };
@@ -46,25 +52,125 @@ pub fn card_grid(props: &CardGridProps) -> Html {
     };
 
     let is_single = cards.len() == 1;
-    let grid_classes = classes!("cards-grid", is_single.then_some("cards-grid--single"));
+    let spread_class = props
+        .reading
+        .spread()
+        .map(|spread| format!("cards-grid--{}", spread.id));
+    let grid_classes = classes!(
+        "cards-grid",
+        spread_class,
+        is_single.then_some("cards-grid--single")
+    );
     let ct = &props.card_translations;
+    let dignities = Dignity::of_reading(&props.reading);
+
+    // Shown so a reading can be reported or rebuilt ("seed 123 shows the wrong card").
+    let seed_caption = props.reading.seed().map(|seed| {
+        format!(
+            "{} · {}",
+            t.format(t.reading.seed, &[("seed", seed.into())]),
+            t.reversal_label(props.reading.reversals())
+        )
+    });
+
+    html! {
+        <>
+            <section class={grid_classes}>
+                if let Some(card) = props.reading.significator() {
+                    { render_significator(card, t, ct) }
+                }
+                { for cards.iter().enumerate().map(|(index, &card)| {
+                    let is_flipped = flipped.get(index).copied().unwrap_or(false);
+                    let toggle = toggle.clone();
+                    let onclick = Callback::from(move |_: MouseEvent| toggle.emit(index));
+                    let clarifiers = render_clarifiers(index, &props.reading, &props.on_clarify, t, ct);
+                    let dignity = dignities.get(index).copied().unwrap_or(Dignity::Neutral);
+                    render_card(index, card, dignity, is_flipped, onclick, clarifiers, t, ct)
+                }) }
+            </section>
+            if let Some(caption) = seed_caption {
+                <p class="reading-seed">{ caption }</p>
+            }
+        </>
+    }
+}
+
+/// The significator sits at the centre of positional layouts and heads the
+/// plain grid. It is always upright and has no meaning to reveal.
+fn render_significator(card: &'static TarotCard, t: &Translations, ct: &CardTranslations) -> Html {
+    let (title, prompt) = t.position_text(&SIGNIFICATOR_POSITION);
+    let drawn = DrawnCard {
+        card,
+        orientation: Orientation::Upright,
+        position: &SIGNIFICATOR_POSITION,
+    };
+    let (name, _, _) = card_text(drawn, t, ct);
+    let area_style = format!("--position-area: {}", SIGNIFICATOR_POSITION.id);
+
+    html! {
+        <div class="card-wrapper card-wrapper--significator" data-position={SIGNIFICATOR_POSITION.id} style={area_style}>
+            <p class="card-position" title={prompt.to_string()}>{ title }</p>
+            <p class="card-title" dir="auto">{ name }</p>
+            <div class="card">
+                <div class="card-inner">
+                    <div class="card-face card-face--front">
+                        <img src={card.image_path()} alt={card.name} loading="lazy" />
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Clarifiers attached under their parent card, plus the button that draws another.
+fn render_clarifiers(
+    parent: usize,
+    reading: &Reading,
+    on_clarify: &Callback<usize>,
+    t: &Translations,
+    ct: &CardTranslations,
+) -> Html {
+    let on_click = on_clarify.reform(move |_: MouseEvent| parent);
 
     html! {
-        <section class={grid_classes}>
-            { for cards.iter().enumerate().map(|(index, &card)| {
-                let is_flipped = flipped.get(index).copied().unwrap_or(false);
-                let toggle = toggle.clone();
-                let onclick = Callback::from(move |_: MouseEvent| toggle.emit(index));
-                render_card(index, card, is_flipped, onclick, t, ct)
+        <div class="card-clarifiers">
+            { for reading.clarifiers_for(parent).map(|clarifier| {
+                let (name, meaning, _) = card_text(*clarifier, t, ct);
+                html! {
+                    <figure class="clarifier">
+                        <img
+                            src={clarifier.image_path()}
+                            alt={clarifier.name()}
+                            loading="lazy"
+                            class={classes!(matches!(clarifier.orientation, Orientation::Reversed).then_some("is-reversed"))}
+                        />
+                        <figcaption>
+                            <span class="clarifier-label">{ &t.reading.clarifier }</span>
+                            <span class="clarifier-name" dir="auto">{ name }</span>
+                            <span class="clarifier-meaning">{ meaning }</span>
+                        </figcaption>
+                    </figure>
+                }
             }) }
-        </section>
+            <button type="button" class="clarify-btn" onclick={on_click}>{ &t.buttons.clarify }</button>
+        </div>
     }
 }
 
-fn render_card(index: usize, card: DrawnCard, is_flipped: bool, onclick: Callback<MouseEvent>, t: &Translations, ct: &CardTranslations) -> Html {
+#[allow(clippy::too_many_arguments)]
+fn render_card(
+    index: usize,
+    card: DrawnCard,
+    dignity: Dignity,
+    is_flipped: bool,
+    onclick: Callback<MouseEvent>,
+    attachments: Html,
+    t: &Translations,
+    ct: &CardTranslations,
+) -> Html {
     let delay_style = format!("transition-delay: {}ms", index * 80);
 
-    let suit_class = detect_suit(card.name());
+    let suit_class = suit_class(card.card.suit());
 
     let stop_propagation = Callback::from(|e: MouseEvent| {
         e.stop_propagation();
@@ -103,28 +209,29 @@ fn render_card(index: usize, card: DrawnCard, is_flipped: bool, onclick: Callbac
     //     Orientation::Reversed => &t.orientation.reversed,
     // };
 
-    // Get translated meaning, keywords, and name - fallback to static card data
-    let (meaning, keywords, card_name): (String, Vec<String>, String) = if let Some(card_t) = ct.get(card.card.slug) {
-        let meaning = match card.orientation {
-            Orientation::Upright => card_t.upright.clone(),
-            Orientation::Reversed => card_t.reversed.clone(),
-        };
-        let name = card_t.name.clone().unwrap_or_else(|| card.card.name.to_string());
-        (meaning, card_t.keywords.clone(), name)
-    } else {
-        // Fallback to static card data
-        (card.meaning().to_string(), card.keywords().iter().map(|s| s.to_string()).collect(), card.card.name.to_string())
-    };
+    let (full_name, meaning, keywords) = card_text(card, t, ct);
 
-    // Build full name with translated "reversed" label
-    let full_name = match card.orientation {
-        Orientation::Upright => card_name,
-        Orientation::Reversed => format!("{} ({})", card_name, &t.orientation.reversed),
+    let correspondence = correspondence(card.card, t, ct);
+    let dignity_badge = match dignity {
+        Dignity::Well => {
+            html! { <span class="dignity-badge dignity-badge--well">{ &t.reading.well_dignified }</span> }
+        }
+        Dignity::Ill => {
+            html! { <span class="dignity-badge dignity-badge--ill">{ &t.reading.ill_dignified }</span> }
+        }
+        Dignity::Neutral => Html::default(),
     };
 
+    let (position_title, position_prompt) = t.position_text(card.position);
+    // Positional layouts in styles.css place each card by its position id.
+    let area_style = format!("--position-area: {}", card.position.id);
+
     html! {
-        <div class="card-wrapper">
-            <p class="card-title">{ full_name }</p>
+        <div class="card-wrapper" data-position={card.position.id} style={area_style}>
+            <p class="card-position">{ position_title }</p>
+            // Names and keywords may fall back to English inside an RTL
+            // language, so each one picks its own direction.
+            <p class="card-title" dir="auto">{ full_name }</p>
             <article
                 class={classes!("card", is_flipped.then_some("is-revealed"))}
                 style={delay_style}
@@ -144,29 +251,62 @@ fn render_card(index: usize, card: DrawnCard, is_flipped: bool, onclick: Callbac
                             // ===== FLIP V2 BUTTON (top position) =====
                             <button class="flip-back-btn-v2" onclick={flip_back_v2}>{ "↩" }</button>
                             // ===== END FLIP V2 BUTTON =====
+                            <p class="card-prompt">{ position_prompt }</p>
+                            <p class="card-correspondence">
+                                <span>{ correspondence }</span>
+                                { dignity_badge }
+                            </p>
                             <p class="card-meaning">{ meaning }</p>
                             <div class="card-keywords">
-                                { for keywords.iter().map(|word| html!{ <span class="keyword-chip">{ word }</span> }) }
+                                { for keywords.iter().map(|word| html!{ <span class="keyword-chip" dir="auto">{ word }</span> }) }
                             </div>
                         </div>
                     </div>
                 </div>
             </article>
+            { attachments }
         </div>
     }
 }
 
-fn detect_suit(name: &str) -> &'static str {
-    if name.contains("Cups") {
-        "suit-cups"
-    } else if name.contains("Pentacles") {
-        "suit-pentacles"
-    } else if name.contains("Swords") {
-        "suit-swords"
-    } else if name.contains("Wands") {
-        "suit-wands"
-    } else {
-        "suit-major"
+/// Translated `(full name, meaning, keywords)` for a drawn card.
+fn card_text(
+    card: DrawnCard,
+    t: &Translations,
+    ct: &CardTranslations,
+) -> (String, String, Vec<String>) {
+    let card_t = ct.get(card.card);
+    let meaning = match card.orientation {
+        Orientation::Upright => card_t.upright,
+        Orientation::Reversed => card_t.reversed,
+    };
+    let keywords = card_t.keywords.iter().map(|s| s.to_string()).collect();
+    let card_name = card_t.name.to_string();
+
+    // Build full name with translated "reversed" label
+    let full_name = match card.orientation {
+        Orientation::Upright => card_name,
+        Orientation::Reversed => format!("{} ({})", card_name, &t.orientation.reversed),
+    };
+
+    (full_name, meaning.to_string(), keywords)
+}
+
+/// "Element · astrology" line for the card back, e.g. "Fire · Mars in Aries".
+fn correspondence(card: &TarotCard, t: &Translations, ct: &CardTranslations) -> String {
+    let element = t.element_name(card.element);
+    match ct.get(card).astrology {
+        Some(astrology) if astrology != element => format!("{element} · {astrology}"),
+        _ => element.to_string(),
     }
 }
 
+fn suit_class(suit: Option<Suit>) -> &'static str {
+    match suit {
+        Some(Suit::Cups) => "suit-cups",
+        Some(Suit::Pentacles) => "suit-pentacles",
+        Some(Suit::Swords) => "suit-swords",
+        Some(Suit::Wands) => "suit-wands",
+        None => "suit-major",
+    }
+}
@@ -0,0 +1,56 @@
+use yew::prelude::*;
+
+use crate::combinations::FoundCombination;
+use crate::deck::{DrawnCard, Orientation};
+use crate::i18n::{CardTranslations, Language, Translations};
+use crate::reading::Reading;
+
+/// Lists the known card pairs found in the reading, with their combined meaning.
+#[derive(Properties, PartialEq)]
+pub struct CombinationsPanelProps {
+    pub reading: Reading,
+    pub language: Language,
+    pub translations: &'static Translations,
+    pub card_translations: &'static CardTranslations,
+}
+
+#[function_component(CombinationsPanel)]
+pub fn combinations_panel(props: &CombinationsPanelProps) -> Html {
+    let found = FoundCombination::in_reading(&props.reading);
+    if found.is_empty() {
+        return Html::default();
+    }
+
+    let t = &props.translations;
+    let cards = props.reading.cards();
+
+    html! {
+        <section class="reading-combinations">
+            <h2>{ &t.insights.combinations }</h2>
+            <ul>
+                { for found.iter().map(|found| {
+                    let (first, second) = found.cards;
+                    html! {
+                        <li>
+                            <p class="combination-cards">
+                                <bdi>{ card_label(&cards[first], t, props.card_translations) }</bdi>
+                                { " + " }
+                                <bdi>{ card_label(&cards[second], t, props.card_translations) }</bdi>
+                            </p>
+                            <p class="combination-text">{ found.combination.text(props.language) }</p>
+                        </li>
+                    }
+                }) }
+            </ul>
+        </section>
+    }
+}
+
+fn card_label(card: &DrawnCard, t: &Translations, ct: &CardTranslations) -> String {
+    let name = ct.get(card.card).name.to_string();
+
+    match card.orientation {
+        Orientation::Upright => name,
+        Orientation::Reversed => format!("{} ({})", name, t.orientation.reversed),
+    }
+}
@@ -1,19 +1,40 @@
 use yew::prelude::*;
 
-use crate::deck::DrawCount;
-use crate::i18n::Translations;
+use crate::deck::{ArcanaFilter, DeckFilter, ReversalPolicy, Spread, Suit, SPREADS};
+use crate::i18n::{Language, Translations};
+use crate::ui::LanguagePicker;
 
 #[derive(Properties, PartialEq)]
 pub struct DrawControlsProps {
-    pub selected: DrawCount,
-    pub on_select: Callback<DrawCount>,
+    pub selected: &'static Spread,
+    pub on_select: Callback<&'static Spread>,
+    pub reversals: ReversalPolicy,
+    pub on_reversals_select: Callback<ReversalPolicy>,
+    pub filter: DeckFilter,
+    pub on_filter_change: Callback<DeckFilter>,
     pub on_draw: Callback<()>,
+    pub on_daily: Callback<()>,
     pub on_copy: Callback<()>,
-    pub on_language_toggle: Callback<()>,
+    pub on_send: Callback<()>,
+    pub on_history: Callback<()>,
+    pub language: Language,
+    pub on_language_change: Callback<Language>,
     pub on_toggle_collapse: Callback<()>,
     pub can_copy: bool,
+    /// Whether the reading can be sent to the chat, i.e. the app runs in Telegram.
+    pub can_send: bool,
+    /// Whether readings are saved here, in CloudStorage or `localStorage`.
+    pub has_history: bool,
+    /// Whether Telegram's MainButton draws, which replaces the Draw button here.
+    /// While it carries Send instead, the Draw button stays.
+    pub native_draw: bool,
+    /// Whether a reading is on the table, which turns Draw into Draw again.
+    pub drawn: bool,
     pub collapsed: bool,
-    pub translations: Translations,
+    pub translations: &'static Translations,
+    /// Extra settings rendered after the deck filter.
+    #[prop_or_default]
+    pub children: Html,
 }
 
 #[function_component(DrawControls)]
@@ -21,10 +42,7 @@ pub fn draw_controls(props: &DrawControlsProps) -> Html {
     let t = &props.translations;
     let collapsed = props.collapsed;
 
-    let section_classes = classes!(
-        "controls",
-        collapsed.then_some("controls--collapsed")
-    );
+    let section_classes = classes!("controls", collapsed.then_some("controls--collapsed"));
 
     html! {
         <section class={section_classes}>
@@ -41,37 +59,61 @@ pub fn draw_controls(props: &DrawControlsProps) -> Html {
 
             // Collapsible content
             <div class="controls-content">
-                <div class="toggle-group" role="radiogroup" aria-label="Choose spread size">
-                    { for DrawCount::ALL.iter().map(|count| render_toggle(*count, props.selected, &props.on_select, t)) }
+                <div class="toggle-group" role="radiogroup" aria-label="Choose spread">
+                    { for SPREADS.iter().map(|spread| render_toggle(spread, props.selected, &props.on_select, t)) }
                 </div>
+                <div class="reversal-group" role="radiogroup" aria-label={t.reversals.label}>
+                    <span class="reversal-group-label">{ &t.reversals.label }</span>
+                    { for ReversalPolicy::PRESETS.iter().map(|policy| render_reversal_option(*policy, props.reversals, &props.on_reversals_select, t)) }
+                </div>
+                { render_deck_filter(&props.filter, &props.on_filter_change, t) }
+                { props.children.clone() }
                 <div class="controls-buttons">
-                    <button type="button" class="button-primary" onclick={props.on_draw.reform(|_| ())}>
-                        { &t.buttons.draw }
+                    if !props.native_draw {
+                        <button type="button" class="button-primary" onclick={props.on_draw.reform(|_| ())}>
+                            { if props.drawn { &t.buttons.draw_again } else { &t.buttons.draw } }
+                        </button>
+                    }
+                    <button type="button" class="button-secondary" onclick={props.on_daily.reform(|_| ())}>
+                        { &t.buttons.daily }
                     </button>
                     <button type="button" class="button-secondary" onclick={props.on_copy.reform(|_| ())} disabled={!props.can_copy}>
                         { &t.buttons.copy }
                     </button>
-                    <button type="button" class="button-language" onclick={props.on_language_toggle.reform(|_| ())}>
-                        { &t.buttons.language }
-                    </button>
+                    if props.can_send {
+                        <button type="button" class="button-secondary" onclick={props.on_send.reform(|_| ())} disabled={!props.can_copy}>
+                            { &t.buttons.send }
+                        </button>
+                    }
+                    if props.has_history {
+                        <button type="button" class="button-secondary" onclick={props.on_history.reform(|_| ())}>
+                            { &t.buttons.history }
+                        </button>
+                    }
+                    <LanguagePicker
+                        selected={props.language}
+                        on_change={props.on_language_change.clone()}
+                        translations={props.translations}
+                    />
                 </div>
             </div>
         </section>
     }
 }
 
-fn render_toggle(count: DrawCount, selected: DrawCount, on_select: &Callback<DrawCount>, t: &Translations) -> Html {
-    let is_active = count == selected;
+fn render_toggle(
+    spread: &'static Spread,
+    selected: &'static Spread,
+    on_select: &Callback<&'static Spread>,
+    t: &Translations,
+) -> Html {
+    let is_active = spread.id == selected.id;
     let on_click = {
         let on_select = on_select.clone();
-        Callback::from(move |_| on_select.emit(count))
+        Callback::from(move |_| on_select.emit(spread))
     };
 
-    let (label, description) = match count {
-        DrawCount::One => (&t.spreads.single.label, &t.spreads.single.description),
-        DrawCount::Three => (&t.spreads.three.label, &t.spreads.three.description),
-        DrawCount::Five => (&t.spreads.five.label, &t.spreads.five.description),
-    };
+    let (label, description) = t.spread_text(spread);
 
     html! {
         <button
@@ -85,3 +127,155 @@ fn render_toggle(count: DrawCount, selected: DrawCount, on_select: &Callback<Dra
         </button>
     }
 }
+
+fn render_reversal_option(
+    policy: ReversalPolicy,
+    selected: ReversalPolicy,
+    on_select: &Callback<ReversalPolicy>,
+    t: &Translations,
+) -> Html {
+    let is_active = policy == selected;
+    let on_click = {
+        let on_select = on_select.clone();
+        Callback::from(move |_| on_select.emit(policy))
+    };
+
+    html! {
+        <button
+            type="button"
+            class={classes!("reversal-option", is_active.then_some("active"))}
+            onclick={on_click}
+            aria-pressed={is_active.to_string()}
+        >
+            { t.reversal_label(policy) }
+        </button>
+    }
+}
+
+/// The deck choices offered in the UI, mapped onto [`DeckFilter`] fields.
+#[derive(Clone, Copy, PartialEq)]
+enum DeckScope {
+    All,
+    Major,
+    Minor,
+    Courts,
+}
+
+impl DeckScope {
+    const ALL: [DeckScope; 4] = [
+        DeckScope::All,
+        DeckScope::Major,
+        DeckScope::Minor,
+        DeckScope::Courts,
+    ];
+
+    fn of(filter: &DeckFilter) -> Self {
+        match (filter.arcana, filter.courts_only) {
+            (ArcanaFilter::Major, _) => DeckScope::Major,
+            (_, true) => DeckScope::Courts,
+            (ArcanaFilter::Minor, false) => DeckScope::Minor,
+            (ArcanaFilter::All, false) => DeckScope::All,
+        }
+    }
+
+    /// Applies the scope while keeping any exclusions (and suits, where they still apply).
+    fn apply(self, filter: &DeckFilter) -> DeckFilter {
+        let (arcana, courts_only) = match self {
+            DeckScope::All => (ArcanaFilter::All, false),
+            DeckScope::Major => (ArcanaFilter::Major, false),
+            DeckScope::Minor => (ArcanaFilter::Minor, false),
+            DeckScope::Courts => (ArcanaFilter::Minor, true),
+        };
+        let suits = if arcana == ArcanaFilter::Minor {
+            filter.suits.clone()
+        } else {
+            Vec::new()
+        };
+
+        DeckFilter {
+            arcana,
+            suits,
+            courts_only,
+            exclude: filter.exclude.clone(),
+        }
+    }
+
+    fn label(self, t: &Translations) -> &str {
+        match self {
+            DeckScope::All => t.deck.all,
+            DeckScope::Major => t.deck.major,
+            DeckScope::Minor => t.deck.minor,
+            DeckScope::Courts => t.deck.courts,
+        }
+    }
+}
+
+fn render_deck_filter(
+    filter: &DeckFilter,
+    on_change: &Callback<DeckFilter>,
+    t: &Translations,
+) -> Html {
+    let scope = DeckScope::of(filter);
+    // Suits only narrow the Minor Arcana, so the chips are offered once majors are out.
+    let show_suits = filter.arcana == ArcanaFilter::Minor;
+
+    html! {
+        <div class="filter-group" role="group" aria-label={t.deck.label}>
+            <span class="filter-group-label">{ &t.deck.label }</span>
+            { for DeckScope::ALL.iter().map(|option| {
+                let is_active = *option == scope;
+                let onclick = {
+                    let on_change = on_change.clone();
+                    let next = option.apply(filter);
+                    Callback::from(move |_| on_change.emit(next.clone()))
+                };
+                html! {
+                    <button
+                        type="button"
+                        class={classes!("filter-option", is_active.then_some("active"))}
+                        {onclick}
+                        aria-pressed={is_active.to_string()}
+                    >
+                        { option.label(t) }
+                    </button>
+                }
+            }) }
+            if show_suits {
+                <div class="filter-suits">
+                    { for Suit::ALL.iter().map(|suit| render_suit_chip(*suit, filter, on_change, t)) }
+                </div>
+            }
+        </div>
+    }
+}
+
+fn render_suit_chip(
+    suit: Suit,
+    filter: &DeckFilter,
+    on_change: &Callback<DeckFilter>,
+    t: &Translations,
+) -> Html {
+    let is_active = filter.suits.contains(&suit);
+    let onclick = {
+        let on_change = on_change.clone();
+        let mut next = filter.clone();
+        if is_active {
+            next.suits.retain(|chosen| *chosen != suit);
+        } else {
+            next.suits.push(suit);
+            next.suits.sort();
+        }
+        Callback::from(move |_| on_change.emit(next.clone()))
+    };
+
+    html! {
+        <button
+            type="button"
+            class={classes!("filter-option", "filter-option--suit", is_active.then_some("active"))}
+            {onclick}
+            aria-pressed={is_active.to_string()}
+        >
+            { t.suit_name(suit) }
+        </button>
+    }
+}
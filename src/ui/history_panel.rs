@@ -0,0 +1,81 @@
+use yew::prelude::*;
+
+use crate::deck::Orientation;
+use crate::history::HistoryEntry;
+use crate::i18n::{CardTranslations, Translations};
+
+/// The saved readings, newest first. Picking one reopens it in the card grid.
+#[derive(Properties, PartialEq)]
+pub struct HistoryPanelProps {
+    pub entries: Vec<HistoryEntry>,
+    pub on_open: Callback<HistoryEntry>,
+    pub on_close: Callback<()>,
+    pub translations: &'static Translations,
+    pub card_translations: &'static CardTranslations,
+}
+
+#[function_component(HistoryPanel)]
+pub fn history_panel(props: &HistoryPanelProps) -> Html {
+    let t = &props.translations;
+
+    html! {
+        <section class="history">
+            <header class="history-header">
+                <h2>{ &t.history.title }</h2>
+                <button type="button" class="button-secondary" onclick={props.on_close.reform(|_| ())}>
+                    { &t.history.close }
+                </button>
+            </header>
+            if props.entries.is_empty() {
+                <p class="history-empty">{ &t.history.empty }</p>
+            } else {
+                <ul class="history-list">
+                    { for props.entries.iter().map(|entry| render_entry(entry, &props.on_open, t, props.card_translations)) }
+                </ul>
+            }
+        </section>
+    }
+}
+
+fn render_entry(
+    entry: &HistoryEntry,
+    on_open: &Callback<HistoryEntry>,
+    t: &Translations,
+    ct: &CardTranslations,
+) -> Html {
+    let on_click = {
+        let on_open = on_open.clone();
+        let entry = entry.clone();
+        Callback::from(move |_: MouseEvent| on_open.emit(entry.clone()))
+    };
+
+    let reading = &entry.reading;
+    let (spread, _) = t.spread_text(reading.spread);
+    let cards = reading
+        .cards
+        .iter()
+        .map(|card| {
+            let name = ct.get(card.card).name.to_string();
+            match card.orientation {
+                Orientation::Upright => name,
+                Orientation::Reversed => format!("{} ({})", name, t.orientation.reversed),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    html! {
+        <li>
+            <button type="button" class="history-entry" onclick={on_click}>
+                <span class="history-entry-meta">
+                    <span class="history-entry-spread">{ spread }</span>
+                    <time class="history-entry-date">{ entry.saved_at_text() }</time>
+                </span>
+                if let Some(question) = &reading.question {
+                    <span class="history-entry-question" dir="auto">{ format!("“{question}”") }</span>
+                }
+                <span class="history-entry-cards" dir="auto">{ cards }</span>
+            </button>
+        </li>
+    }
+}
@@ -0,0 +1,109 @@
+use yew::prelude::*;
+
+use crate::i18n::{CardTranslations, Translations};
+use crate::insights::ReadingInsights;
+use crate::reading::Reading;
+
+/// Summary shown under the cards so a multi-card spread reads as a whole.
+#[derive(Properties, PartialEq)]
+pub struct InsightsPanelProps {
+    pub reading: Reading,
+    pub translations: &'static Translations,
+    pub card_translations: &'static CardTranslations,
+}
+
+#[function_component(InsightsPanel)]
+pub fn insights_panel(props: &InsightsPanelProps) -> Html {
+    // A single card has no balance to speak of.
+    let Some(insights) = ReadingInsights::new(&props.reading).filter(|insights| insights.total > 1)
+    else {
+        return Html::default();
+    };
+    let t = &props.translations;
+    let ct = &props.card_translations;
+
+    let arcana = t.format(
+        t.insights.arcana,
+        &[
+            ("major", insights.majors.into()),
+            ("minor", insights.minors.into()),
+        ],
+    );
+
+    let dominant = insights.dominant_suit.map(|suit| {
+        t.format(
+            t.insights.dominant,
+            &[
+                ("suit", t.suit_name(suit).into()),
+                ("element", t.element_name(suit.element()).into()),
+            ],
+        )
+    });
+
+    let missing = (!insights.missing_suits.is_empty()).then(|| {
+        let suits: Vec<&str> = insights
+            .missing_suits
+            .iter()
+            .map(|&suit| t.suit_name(suit))
+            .collect();
+        let count = suits.len();
+        t.format(
+            t.insights.missing,
+            &[
+                ("count", count.into()),
+                ("suits", (&suits.join(", ")).into()),
+            ],
+        )
+    });
+
+    let reversed = t.format(
+        t.insights.reversed,
+        &[("percent", insights.reversed_percent().into())],
+    );
+
+    let repeated = (!insights.repeated_ranks.is_empty()).then(|| {
+        let ranks: Vec<String> = insights
+            .repeated_ranks
+            .iter()
+            .map(|&(rank, count)| format!("{} ×{}", t.rank_name(rank), count))
+            .collect();
+        t.format(
+            t.insights.repeated,
+            &[("ranks", (&ranks.join(", ")).into())],
+        )
+    });
+
+    let numerology = insights.numerology.card().map(|card| {
+        let numerology = &insights.numerology;
+        t.format(
+            t.insights.numerology,
+            &[
+                ("total", numerology.total.into()),
+                ("reduced", numerology.reduced.into()),
+                ("card", ct.get(card).name.into()),
+            ],
+        )
+    });
+
+    html! {
+        <section class="reading-insights">
+            <h2>{ &t.insights.title }</h2>
+            <ul>
+                <li>{ arcana }</li>
+                if let Some(line) = dominant {
+                    <li>{ line }</li>
+                }
+                if let Some(line) = missing {
+                    <li>{ line }</li>
+                }
+                <li>{ reversed }</li>
+                if let Some(line) = repeated {
+                    <li>{ line }</li>
+                }
+                if let Some(line) = numerology {
+                    <li>{ line }</li>
+                }
+            </ul>
+        </section>
+    }
+}
@@ -0,0 +1,35 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::i18n::{Language, Translations};
+
+/// Every language in the registry, by flag and native name.
+#[derive(Properties, PartialEq)]
+pub struct LanguagePickerProps {
+    pub selected: Language,
+    pub on_change: Callback<Language>,
+    pub translations: &'static Translations,
+}
+
+#[function_component(LanguagePicker)]
+pub fn language_picker(props: &LanguagePickerProps) -> Html {
+    let t = &props.translations;
+
+    let on_change = props.on_change.reform(|e: Event| {
+        Language::from_code(&e.target_unchecked_into::<HtmlSelectElement>().value())
+    });
+
+    html! {
+        <select class="button-language" aria-label={t.buttons.language} onchange={on_change}>
+            { for Language::all().map(|language| html! {
+                <option
+                    value={language.code()}
+                    dir={language.direction().as_str()}
+                    selected={language == props.selected}
+                >
+                    { language.label() }
+                </option>
+            }) }
+        </select>
+    }
+}
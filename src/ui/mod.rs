@@ -1,7 +1,21 @@
 pub mod card_grid;
+pub mod combinations_panel;
 pub mod draw_controls;
+pub mod history_panel;
+pub mod insights_panel;
+pub mod language_picker;
+pub mod significator_picker;
 pub mod status_banner;
+pub mod verdict_panel;
+pub mod yes_no_controls;
 
 pub use card_grid::CardGrid;
+pub use combinations_panel::CombinationsPanel;
 pub use draw_controls::DrawControls;
+pub use history_panel::HistoryPanel;
+pub use insights_panel::InsightsPanel;
+pub use language_picker::LanguagePicker;
+pub use significator_picker::SignificatorPicker;
 pub use status_banner::StatusBanner;
+pub use verdict_panel::VerdictPanel;
+pub use yes_no_controls::YesNoControls;
@@ -0,0 +1,96 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::deck::{Arcana, Rank, Suit, TarotCard, CARDS};
+use crate::i18n::{CardTranslations, Translations};
+
+/// Lets the querent choose a significator, either by browsing the whole deck
+/// or by the traditional court card + suit rule.
+#[derive(Properties, PartialEq)]
+pub struct SignificatorPickerProps {
+    pub selected: Option<&'static TarotCard>,
+    pub on_change: Callback<Option<&'static TarotCard>>,
+    pub translations: &'static Translations,
+    pub card_translations: &'static CardTranslations,
+}
+
+#[function_component(SignificatorPicker)]
+pub fn significator_picker(props: &SignificatorPickerProps) -> Html {
+    let t = &props.translations;
+    let ct = &props.card_translations;
+    let selected_slug = props.selected.map(|card| card.slug).unwrap_or_default();
+    let (selected_rank, selected_suit) = match props.selected.map(|card| card.arcana) {
+        Some(Arcana::Minor { suit, rank }) if rank.is_court() => (Some(rank), Some(suit)),
+        _ => (None, None),
+    };
+
+    let on_browse = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let slug = e.target_unchecked_into::<HtmlSelectElement>().value();
+            on_change.emit(CARDS.iter().find(|card| card.slug == slug));
+        })
+    };
+
+    // Picking a rank keeps the current suit (Cups by default) and vice versa.
+    let on_rank = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let index = e
+                .target_unchecked_into::<HtmlSelectElement>()
+                .value()
+                .parse::<usize>()
+                .ok();
+            let rank = index.and_then(|index| Rank::COURTS.get(index).copied());
+            let suit = selected_suit.unwrap_or(Suit::Cups);
+            on_change.emit(rank.and_then(|rank| TarotCard::court(rank, suit)));
+        })
+    };
+
+    let on_suit = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let index = e
+                .target_unchecked_into::<HtmlSelectElement>()
+                .value()
+                .parse::<usize>()
+                .ok();
+            let suit = index.and_then(|index| Suit::ALL.get(index).copied());
+            let rank = selected_rank.unwrap_or(Rank::Page);
+            on_change.emit(suit.and_then(|suit| TarotCard::court(rank, suit)));
+        })
+    };
+
+    html! {
+        <div class="significator-picker">
+            <span class="filter-group-label">{ &t.significator.label }</span>
+            <div class="significator-rules">
+                <select aria-label={t.significator.court} onchange={on_rank}>
+                    <option value="" selected={selected_rank.is_none()}>{ &t.significator.court }</option>
+                    { for Rank::COURTS.iter().enumerate().map(|(index, rank)| html! {
+                        <option value={index.to_string()} selected={selected_rank == Some(*rank)}>
+                            { t.court_rank_name(*rank) }
+                        </option>
+                    }) }
+                </select>
+                <select aria-label={t.significator.suit} onchange={on_suit}>
+                    <option value="" selected={selected_suit.is_none()}>{ &t.significator.suit }</option>
+                    { for Suit::ALL.iter().enumerate().map(|(index, suit)| html! {
+                        <option value={index.to_string()} selected={selected_suit == Some(*suit)}>
+                            { t.suit_name(*suit) }
+                        </option>
+                    }) }
+                </select>
+            </div>
+            <select class="significator-browse" aria-label={t.significator.label} onchange={on_browse}>
+                <option value="" selected={selected_slug.is_empty()}>{ &t.significator.none }</option>
+                { for CARDS.iter().map(|card| {
+                    let name = ct.get(card).name.to_string();
+                    html! {
+                        <option value={card.slug} selected={card.slug == selected_slug}>{ name }</option>
+                    }
+                }) }
+            </select>
+        </div>
+    }
+}
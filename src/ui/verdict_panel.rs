@@ -0,0 +1,68 @@
+use yew::prelude::*;
+
+use crate::deck::{Answer, Orientation};
+use crate::i18n::{CardTranslations, Translations};
+use crate::oracle::Verdict;
+use crate::reading::Reading;
+
+/// The answer of a Yes/No reading, with the cards that voted for it.
+#[derive(Properties, PartialEq)]
+pub struct VerdictPanelProps {
+    pub reading: Reading,
+    pub translations: &'static Translations,
+    pub card_translations: &'static CardTranslations,
+}
+
+#[function_component(VerdictPanel)]
+pub fn verdict_panel(props: &VerdictPanelProps) -> Html {
+    let Some(verdict) = Verdict::of_reading(&props.reading) else {
+        return Html::default();
+    };
+    let t = &props.translations;
+    let ct = &props.card_translations;
+
+    let tally = t.format(
+        t.yes_no.tally,
+        &[
+            ("yes", verdict.yes.into()),
+            ("no", verdict.no.into()),
+            ("maybe", verdict.maybe.into()),
+        ],
+    );
+
+    html! {
+        <section class={classes!("yes-no-verdict", answer_class(verdict.answer))}>
+            if let Some(question) = props.reading.question() {
+                <p class="yes-no-verdict-question">{ format!("“{question}”") }</p>
+            }
+            <p class="yes-no-verdict-label">{ &t.yes_no.verdict }</p>
+            <h2 class="yes-no-verdict-answer">{ t.answer_name(verdict.answer) }</h2>
+            if props.reading.cards().len() > 1 {
+                <p class="yes-no-verdict-tally">{ tally }</p>
+            }
+            <ul class="yes-no-verdict-cards">
+                { for props.reading.cards().iter().map(|card| {
+                    let name = ct.get(card.card).name.to_string();
+                    let name = match card.orientation {
+                        Orientation::Upright => name,
+                        Orientation::Reversed => format!("{} ({})", name, t.orientation.reversed),
+                    };
+                    html! {
+                        <li class={answer_class(card.answer())}>
+                            <span dir="auto">{ name }</span>
+                            <span class="yes-no-verdict-card-answer">{ t.answer_name(card.answer()) }</span>
+                        </li>
+                    }
+                }) }
+            </ul>
+        </section>
+    }
+}
+
+fn answer_class(answer: Answer) -> &'static str {
+    match answer {
+        Answer::Yes => "answer-yes",
+        Answer::No => "answer-no",
+        Answer::Maybe => "answer-maybe",
+    }
+}
@@ -0,0 +1,53 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::deck::{Spread, YES_NO_SPREADS};
+use crate::i18n::Translations;
+
+/// The Yes/No oracle: a closed question and one button per layout
+/// (one card or three).
+#[derive(Properties, PartialEq)]
+pub struct YesNoControlsProps {
+    pub question: String,
+    pub on_question_change: Callback<String>,
+    pub on_ask: Callback<&'static Spread>,
+    pub translations: &'static Translations,
+}
+
+#[function_component(YesNoControls)]
+pub fn yes_no_controls(props: &YesNoControlsProps) -> Html {
+    let t = &props.translations;
+
+    let on_input = props
+        .on_question_change
+        .reform(|e: InputEvent| e.target_unchecked_into::<HtmlInputElement>().value());
+
+    html! {
+        <div class="yes-no-controls">
+            <span class="filter-group-label">{ &t.yes_no.label }</span>
+            <input
+                type="text"
+                class="yes-no-question"
+                value={props.question.clone()}
+                placeholder={t.yes_no.placeholder}
+                aria-label={t.yes_no.question}
+                oninput={on_input}
+            />
+            <div class="yes-no-buttons">
+                { for YES_NO_SPREADS.iter().map(|spread| {
+                    let (label, description) = t.spread_text(spread);
+                    html! {
+                        <button
+                            type="button"
+                            class="filter-option"
+                            title={description.to_string()}
+                            onclick={props.on_ask.reform(move |_| spread)}
+                        >
+                            { label }
+                        </button>
+                    }
+                }) }
+            </div>
+        </div>
+    }
+}